@@ -19,3 +19,6 @@ pub mod types;
 
 mod pkx;
 pub use pkx::*;
+
+mod convert;
+pub use convert::*;
@@ -0,0 +1,505 @@
+use super::{
+    pkx::{read_utf16_string, write_utf16_string, Name, Pkx},
+    poke_crypto, types,
+};
+use core::convert::TryInto;
+use no_std_io::{Reader, Writer};
+use safe_transmute::TriviallyTransmutable;
+
+pub type Pa8Bytes = [u8; Pa8::STORED_SIZE];
+
+pub struct Pa8 {
+    data: Pa8Bytes,
+}
+
+impl Pa8 {
+    pub const STORED_SIZE: usize = 360;
+    pub const BLOCK_SIZE: usize = 88;
+
+    pub fn new(data: [u8; Pa8::STORED_SIZE]) -> Self {
+        let seed_bytes: [u8; 4] = data[0..4].try_into().unwrap();
+        let seed = u32::from_le_bytes(seed_bytes);
+        Self {
+            data: poke_crypto::decrypt::<{ Pa8::STORED_SIZE }, { Pa8::BLOCK_SIZE }>(data, seed),
+        }
+    }
+
+    /// Re-encrypts this entity back into its stored `.ea8` form, recomputing
+    /// the checksum over the block region first.
+    pub fn encrypt(&self) -> Pa8Bytes {
+        let mut data = self.data;
+        let checksum = self.calculate_checksum();
+        data[0x06..0x08].copy_from_slice(&checksum.to_le_bytes());
+        poke_crypto::encrypt::<{ Pa8::STORED_SIZE }, { Pa8::BLOCK_SIZE }>(data)
+    }
+}
+
+impl Reader for Pa8 {
+    fn get_slice(&self) -> &[u8] {
+        &self.data
+    }
+}
+
+impl Writer for Pa8 {
+    fn get_mut_slice(&mut self) -> &mut [u8] {
+        &mut self.data
+    }
+}
+
+impl Pkx for Pa8 {
+    fn encryption_constant(&self) -> u32 {
+        self.default_read_le(0x00)
+    }
+
+    fn set_encryption_constant(&mut self, encryption_constant: u32) {
+        self.default_write_le(0x00, encryption_constant);
+    }
+
+    fn checksum(&self) -> u16 {
+        self.default_read_le(0x06)
+    }
+
+    fn set_checksum(&mut self, checksum: u16) {
+        self.default_write_le(0x06, checksum);
+    }
+
+    fn species(&self) -> types::Species {
+        self.default_read_le::<u16>(0x08).into()
+    }
+
+    fn set_species(&mut self, species: types::Species) {
+        self.default_write_le::<u16>(0x08, species.into());
+    }
+
+    fn tid(&self) -> u16 {
+        self.default_read_le(0x0C)
+    }
+
+    fn set_tid(&mut self, tid: u16) {
+        self.default_write_le(0x0C, tid);
+    }
+
+    fn sid(&self) -> u16 {
+        self.default_read_le(0x0E)
+    }
+
+    fn set_sid(&mut self, sid: u16) {
+        self.default_write_le(0x0E, sid);
+    }
+
+    fn ability(&self) -> types::Ability {
+        let ability: u8 = self.default_read(0x14);
+        (ability as u16).into()
+    }
+
+    fn set_ability(&mut self, ability: types::Ability) {
+        self.default_write(0x14, u16::from(ability) as u8);
+    }
+
+    fn ability_number(&self) -> types::AbilityNumber {
+        self.default_read::<u8>(0x15).into()
+    }
+
+    fn set_ability_number(&mut self, ability_number: types::AbilityNumber) {
+        self.default_write(0x15, u8::from(ability_number));
+    }
+
+    fn pid(&self) -> u32 {
+        self.default_read_le(0x18)
+    }
+
+    fn set_pid(&mut self, pid: u32) {
+        self.default_write_le(0x18, pid);
+    }
+
+    fn nature(&self) -> types::Nature {
+        self.default_read::<u8>(0x1C).into()
+    }
+
+    fn set_nature(&mut self, nature: types::Nature) {
+        self.default_write(0x1C, u8::from(nature));
+    }
+
+    fn gender(&self) -> types::Gender {
+        let byte = self.default_read::<u8>(0x1D);
+        ((byte >> 1) & 3).into()
+    }
+
+    fn set_gender(&mut self, gender: types::Gender) {
+        let byte = self.default_read::<u8>(0x1D);
+        let gender_bits = u8::from(gender) & 3;
+        self.default_write(0x1D, (byte & !0x06) | (gender_bits << 1));
+    }
+
+    fn evs(&self) -> types::Stats {
+        types::Stats {
+            hp: self.default_read(0x1E),
+            atk: self.default_read(0x1F),
+            def: self.default_read(0x20),
+            spa: self.default_read(0x21),
+            spd: self.default_read(0x22),
+            spe: self.default_read(0x23),
+        }
+    }
+
+    fn set_evs(&mut self, evs: types::Stats) {
+        self.default_write(0x1E, evs.hp);
+        self.default_write(0x1F, evs.atk);
+        self.default_write(0x20, evs.def);
+        self.default_write(0x21, evs.spa);
+        self.default_write(0x22, evs.spd);
+        self.default_write(0x23, evs.spe);
+    }
+
+    // Block A (species..evs, above) keeps Pk6's offsets: it starts at the
+    // same 0x08 regardless of generation and every field on it falls well
+    // inside the first BLOCK_SIZE (88) bytes either way. Block B onward
+    // moved: Pa8's 88-byte blocks are 32 bytes wider than Pk6's, so each
+    // later block starts 32/64/96 bytes later than its Pk6 counterpart.
+
+    fn move1(&self) -> types::Move {
+        self.default_read::<u16>(0x7A).into()
+    }
+
+    fn move2(&self) -> types::Move {
+        self.default_read::<u16>(0x7C).into()
+    }
+
+    fn move3(&self) -> types::Move {
+        self.default_read::<u16>(0x7E).into()
+    }
+
+    fn move4(&self) -> types::Move {
+        self.default_read::<u16>(0x80).into()
+    }
+
+    fn set_moves(
+        &mut self,
+        move1: types::Move,
+        move2: types::Move,
+        move3: types::Move,
+        move4: types::Move,
+    ) {
+        self.default_write::<u16>(0x7A, move1.into());
+        self.default_write::<u16>(0x7C, move2.into());
+        self.default_write::<u16>(0x7E, move3.into());
+        self.default_write::<u16>(0x80, move4.into());
+    }
+
+    fn iv32(&self) -> u32 {
+        self.default_read_le(0x94)
+    }
+
+    fn set_iv32(&mut self, iv32: u32) {
+        self.default_write_le(0x94, iv32);
+    }
+
+    fn ht_friendship(&self) -> u32 {
+        self.default_read(0xE2)
+    }
+
+    fn set_ht_friendship(&mut self, ht_friendship: u32) {
+        self.default_write(0xE2, ht_friendship);
+    }
+
+    fn ot_friendship(&self) -> u32 {
+        self.default_read(0x12A)
+    }
+
+    fn set_ot_friendship(&mut self, ot_friendship: u32) {
+        self.default_write(0x12A, ot_friendship);
+    }
+
+    fn language(&self) -> types::Language {
+        self.default_read::<u8>(0x143).into()
+    }
+
+    fn set_language(&mut self, language: types::Language) {
+        self.default_write(0x143, u8::from(language));
+    }
+
+    fn nickname(&self) -> Name {
+        read_utf16_string(self.get_slice(), 0x60, 12)
+    }
+
+    fn set_nickname(&mut self, nickname: &str) {
+        write_utf16_string(self.get_mut_slice(), 0x60, 13, nickname);
+    }
+
+    fn ot_name(&self) -> Name {
+        read_utf16_string(self.get_slice(), 0x110, 9)
+    }
+
+    fn set_ot_name(&mut self, ot_name: &str) {
+        write_utf16_string(self.get_mut_slice(), 0x110, 10, ot_name);
+    }
+
+    fn ht_name(&self) -> Name {
+        read_utf16_string(self.get_slice(), 0xB8, 8)
+    }
+
+    fn set_ht_name(&mut self, ht_name: &str) {
+        write_utf16_string(self.get_mut_slice(), 0xB8, 9, ht_name);
+    }
+
+    fn species_max(&self) -> types::Species {
+        // Legends: Arceus shipped alongside Gen 8 and has no dedicated
+        // Pokédex cap constant of its own yet; Gen 8's is the closest
+        // known upper bound.
+        types::Species::MAX_GEN8
+    }
+
+    fn move_max(&self) -> types::Move {
+        types::Move::MAX_GEN8
+    }
+
+    fn ability_max(&self) -> types::Ability {
+        types::Ability::MAX_GEN8
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Pa8Data(Pa8Bytes);
+
+// This is safe because the bytes in Pa8Data can be anything
+unsafe impl TriviallyTransmutable for Pa8Data {}
+
+impl From<Pa8Data> for Pa8 {
+    fn from(data: Pa8Data) -> Self {
+        Self::new(data.0)
+    }
+}
+
+impl Default for Pa8Data {
+    fn default() -> Self {
+        Self([0; Pa8::STORED_SIZE])
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const TEST_EKX: Pa8Bytes = [
+        0x4d, 0x3c, 0x2b, 0x1a, 0x00, 0x00, 0xfc, 0x06, 0xd2, 0x81, 0x3a, 0x2a, 0x85, 0x9a, 0xf0,
+        0x43, 0xf2, 0x8e, 0x84, 0x51, 0x8d, 0x56, 0x6e, 0xc2, 0xc2, 0xf3, 0x5b, 0xe1, 0x3a, 0x4d,
+        0x4d, 0x13, 0xe2, 0x35, 0x76, 0x5c, 0x00, 0x44, 0x24, 0x4b, 0x72, 0x4c, 0xa1, 0xf2, 0xae,
+        0x6e, 0x8c, 0x9f, 0xd1, 0x8d, 0xbe, 0x5b, 0x3a, 0x97, 0x55, 0x00, 0xbb, 0x69, 0x46, 0xbe,
+        0xdc, 0x2c, 0xc9, 0x18, 0xc7, 0x05, 0x1f, 0xb2, 0xd3, 0xb3, 0x7f, 0x52, 0xca, 0x34, 0x2e,
+        0x0a, 0x62, 0xb1, 0x10, 0x8b, 0x79, 0xac, 0x30, 0xa3, 0x1a, 0x54, 0x10, 0x71, 0x53, 0xf1,
+        0xca, 0x4b, 0x5e, 0x5c, 0x3c, 0xbc, 0xf5, 0x1c, 0xb4, 0x19, 0x57, 0x09, 0x9a, 0xb4, 0x07,
+        0xe1, 0x3f, 0xc1, 0xbe, 0x0c, 0xb5, 0xbe, 0x7e, 0x72, 0x8b, 0xbe, 0x46, 0xcf, 0xf4, 0xda,
+        0x17, 0x2e, 0xd7, 0x6f, 0xd8, 0x7d, 0xaf, 0x54, 0x06, 0x39, 0x5f, 0x5f, 0x9c, 0xb2, 0x6b,
+        0x0e, 0xaf, 0x5f, 0xf0, 0x70, 0xaa, 0xb1, 0x2f, 0x21, 0xeb, 0xef, 0x2e, 0xa3, 0x7a, 0x7c,
+        0x6a, 0xfc, 0x79, 0x66, 0xdb, 0x94, 0xa1, 0x6f, 0x06, 0xac, 0x22, 0x17, 0x91, 0x13, 0x25,
+        0x9b, 0x27, 0x62, 0x1e, 0x33, 0x5e, 0x4c, 0xf1, 0xcf, 0x93, 0x33, 0xb5, 0xd1, 0x95, 0x71,
+        0xe2, 0xaf, 0xca, 0xbf, 0x68, 0xdf, 0x89, 0xe9, 0x35, 0xd8, 0x1a, 0x4f, 0xba, 0x15, 0x9f,
+        0x5c, 0xe8, 0xb9, 0x99, 0x6a, 0x8c, 0x65, 0x66, 0x2f, 0x86, 0x28, 0xcb, 0x62, 0x4d, 0x6d,
+        0xe7, 0x48, 0xa5, 0xe7, 0xa5, 0xc3, 0x19, 0xeb, 0xfb, 0x2c, 0xc9, 0xb6, 0x38, 0xdf, 0x1a,
+        0x46, 0xf8, 0xc5, 0x4d, 0xa2, 0x74, 0xe9, 0x0a, 0x97, 0x46, 0x6d, 0x62, 0x9c, 0xdd, 0x56,
+        0xd4, 0xd2, 0x32, 0x15, 0x56, 0xa2, 0x7e, 0x72, 0x7a, 0xfb, 0x01, 0x7e, 0x19, 0xf2, 0x1d,
+        0x5f, 0x38, 0x7b, 0x48, 0x10, 0xfd, 0x13, 0xaf, 0xf7, 0xe9, 0x0d, 0x7a, 0xaa, 0xbd, 0xbc,
+        0x0d, 0x43, 0xaa, 0xdc, 0xc2, 0x5f, 0xc0, 0xcb, 0xe8, 0x6e, 0xbc, 0xaf, 0x13, 0x2e, 0x9e,
+        0xe9, 0xfa, 0x85, 0xae, 0x5a, 0x7c, 0x01, 0xe2, 0x8a, 0x7e, 0x55, 0xbb, 0xc6, 0xc0, 0x98,
+        0x11, 0x5b, 0xe1, 0x35, 0x70, 0x3f, 0x25, 0x1f, 0xeb, 0x53, 0xb9, 0xaa, 0x91, 0xe5, 0x8c,
+        0x8f, 0xf0, 0x04, 0xf6, 0x0d, 0x3d, 0x6c, 0xc1, 0x34, 0x25, 0xcf, 0x56, 0xba, 0x50, 0x84,
+        0x14, 0xdb, 0x17, 0x19, 0x1d, 0x69, 0x08, 0x9a, 0x27, 0x87, 0x1f, 0xbf, 0x06, 0xb7, 0xea,
+        0xc8, 0x0a, 0xc6, 0xe8, 0x43, 0x74, 0x04, 0xe6, 0x29, 0x84, 0xea, 0x2e, 0x85, 0xec, 0x24,
+    ];
+
+    #[test]
+    fn should_decrypt() {
+        let result: Pa8Bytes = [
+            0x4d, 0x3c, 0x2b, 0x1a, 0x00, 0x00, 0xfc, 0x06, 0x84, 0x00, 0x00, 0x00, 0x61, 0x1e,
+            0xb8, 0x22, 0x00, 0x00, 0x00, 0x00, 0x96, 0x04, 0x00, 0x00, 0xdd, 0xcc, 0xbb, 0xaa,
+            0x03, 0x04, 0xfc, 0x00, 0x06, 0xfc, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x48, 0x00,
+            0x69, 0x00, 0x73, 0x00, 0x75, 0x00, 0x69, 0x00, 0x20, 0x00, 0x46, 0x00, 0x6f, 0x00,
+            0x72, 0x00, 0x6d, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x90, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xff, 0xff, 0xff, 0x3f, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x41, 0x00, 0x6b, 0x00, 0x61, 0x00, 0x72, 0x00,
+            0x69, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        ];
+
+        let pkx = Pa8::new(TEST_EKX);
+        assert_eq!(pkx.get_slice(), result)
+    }
+
+    #[test]
+    fn pa8_data_size_should_be_360() {
+        assert_eq!(core::mem::size_of::<Pa8Data>(), Pa8::STORED_SIZE);
+    }
+
+    #[test]
+    fn should_read_species() {
+        let pkx = Pa8::new(TEST_EKX);
+        assert_eq!(pkx.species(), types::Species::DITTO);
+    }
+
+    #[test]
+    fn should_read_pid() {
+        let pkx = Pa8::new(TEST_EKX);
+        assert_eq!(pkx.pid(), 0xAABBCCDD)
+    }
+
+    #[test]
+    fn should_read_tid() {
+        let pkx = Pa8::new(TEST_EKX);
+        assert_eq!(pkx.tid(), 7777)
+    }
+
+    #[test]
+    fn should_read_sid() {
+        let pkx = Pa8::new(TEST_EKX);
+        assert_eq!(pkx.sid(), 8888)
+    }
+
+    #[test]
+    fn should_read_nature() {
+        let pkx = Pa8::new(TEST_EKX);
+        assert_eq!(pkx.nature(), types::Nature::ADAMANT);
+    }
+
+    #[test]
+    fn should_read_ability_number() {
+        let pkx = Pa8::new(TEST_EKX);
+        assert_eq!(pkx.ability_number(), types::AbilityNumber::HIDDEN)
+    }
+
+    #[test]
+    fn should_read_gender() {
+        let pkx = Pa8::new(TEST_EKX);
+        assert_eq!(pkx.gender(), types::Gender::Genderless)
+    }
+
+    #[test]
+    fn should_read_move1() {
+        let pkx = Pa8::new(TEST_EKX);
+        assert_eq!(pkx.move1(), types::Move(144))
+    }
+
+    #[test]
+    fn should_read_ivs() {
+        let pkx = Pa8::new(TEST_EKX);
+        let stats = types::Stats {
+            hp: 31,
+            atk: 31,
+            def: 31,
+            spa: 31,
+            spd: 31,
+            spe: 31,
+        };
+        assert_eq!(pkx.ivs(), stats)
+    }
+
+    #[test]
+    fn should_read_evs() {
+        let pkx = Pa8::new(TEST_EKX);
+        let stats = types::Stats {
+            hp: 252,
+            atk: 0,
+            def: 6,
+            spa: 252,
+            spd: 0,
+            spe: 0,
+        };
+        assert_eq!(pkx.evs(), stats)
+    }
+
+    #[test]
+    fn should_read_checksum() {
+        let pkx = Pa8::new(TEST_EKX);
+        assert_eq!(pkx.checksum(), 0x06fc);
+    }
+
+    #[test]
+    fn should_calculate_matching_checksum() {
+        let pkx = Pa8::new(TEST_EKX);
+        assert_eq!(pkx.calculate_checksum(), pkx.checksum());
+    }
+
+    #[test]
+    fn should_be_valid() {
+        let pkx = Pa8::new(TEST_EKX);
+        assert!(pkx.is_valid());
+    }
+
+    #[test]
+    fn should_be_invalid_after_species_hacked_in() {
+        let mut pkx = Pa8::new(TEST_EKX);
+        pkx.set_species(types::Species(9001));
+        assert!(!pkx.is_valid());
+    }
+
+    #[test]
+    fn should_read_nickname() {
+        let pkx = Pa8::new(TEST_EKX);
+        assert_eq!(pkx.nickname(), "Hisui Form");
+    }
+
+    #[test]
+    fn should_read_ot_name() {
+        let pkx = Pa8::new(TEST_EKX);
+        assert_eq!(pkx.ot_name(), "Akari");
+    }
+
+    #[test]
+    fn should_round_trip_encryption_unmodified() {
+        let pkx = Pa8::new(TEST_EKX);
+        let reencrypted = pkx.encrypt();
+        let roundtripped = Pa8::new(reencrypted);
+        assert_eq!(roundtripped.get_slice(), pkx.get_slice());
+    }
+
+    #[test]
+    fn should_round_trip_encryption_after_mutation() {
+        let mut pkx = Pa8::new(TEST_EKX);
+        let evs = types::Stats {
+            hp: 4,
+            atk: 252,
+            def: 0,
+            spa: 0,
+            spd: 0,
+            spe: 252,
+        };
+        pkx.set_species(types::Species::MEW);
+        pkx.set_evs(evs);
+        pkx.set_moves(
+            types::Move::TRANSFORM,
+            types::Move::NONE,
+            types::Move::NONE,
+            types::Move::NONE,
+        );
+        pkx.set_iv32(pkx.iv32());
+
+        let reencrypted = pkx.encrypt();
+        let roundtripped = Pa8::new(reencrypted);
+
+        assert_eq!(roundtripped.species(), types::Species::MEW);
+        assert_eq!(roundtripped.evs(), evs);
+    }
+
+    #[test]
+    fn should_round_trip_ot_name_without_truncation() {
+        let mut pkx = Pa8::new(TEST_EKX);
+        pkx.set_ot_name("Ditto is ");
+        assert_eq!(pkx.ot_name(), "Ditto is ");
+    }
+}
@@ -1,6 +1,9 @@
-use super::{pkx::Pkx, poke_crypto, types};
+use super::{
+    pkx::{read_utf16_string, write_utf16_string, Name, Pkx},
+    poke_crypto, types,
+};
 use core::convert::TryInto;
-use no_std_io::Reader;
+use no_std_io::{Reader, Writer};
 use safe_transmute::TriviallyTransmutable;
 
 pub type Pk6Bytes = [u8; Pk6::STORED_SIZE];
@@ -20,6 +23,15 @@ impl Pk6 {
             data: poke_crypto::decrypt::<{ Pk6::STORED_SIZE }, { Pk6::BLOCK_SIZE }>(data, seed),
         }
     }
+
+    /// Re-encrypts this entity back into its stored `.ek6` form, recomputing
+    /// the checksum over the block region first.
+    pub fn encrypt(&self) -> Pk6Bytes {
+        let mut data = self.data;
+        let checksum = self.calculate_checksum();
+        data[0x06..0x08].copy_from_slice(&checksum.to_le_bytes());
+        poke_crypto::encrypt::<{ Pk6::STORED_SIZE }, { Pk6::BLOCK_SIZE }>(data)
+    }
 }
 
 impl Reader for Pk6 {
@@ -28,45 +40,97 @@ impl Reader for Pk6 {
     }
 }
 
+impl Writer for Pk6 {
+    fn get_mut_slice(&mut self) -> &mut [u8] {
+        &mut self.data
+    }
+}
+
 impl Pkx for Pk6 {
     fn encryption_constant(&self) -> u32 {
         self.default_read_le(0x00)
     }
 
+    fn set_encryption_constant(&mut self, encryption_constant: u32) {
+        self.default_write_le(0x00, encryption_constant);
+    }
+
+    fn checksum(&self) -> u16 {
+        self.default_read_le(0x06)
+    }
+
+    fn set_checksum(&mut self, checksum: u16) {
+        self.default_write_le(0x06, checksum);
+    }
+
     fn species(&self) -> types::Species {
         self.default_read_le::<u16>(0x08).into()
     }
 
+    fn set_species(&mut self, species: types::Species) {
+        self.default_write_le::<u16>(0x08, species.into());
+    }
+
     fn tid(&self) -> u16 {
         self.default_read_le(0x0C)
     }
 
+    fn set_tid(&mut self, tid: u16) {
+        self.default_write_le(0x0C, tid);
+    }
+
     fn sid(&self) -> u16 {
         self.default_read_le(0x0E)
     }
 
+    fn set_sid(&mut self, sid: u16) {
+        self.default_write_le(0x0E, sid);
+    }
+
     fn ability(&self) -> types::Ability {
         let ability: u8 = self.default_read(0x14);
         (ability as u16).into()
     }
 
+    fn set_ability(&mut self, ability: types::Ability) {
+        self.default_write(0x14, u16::from(ability) as u8);
+    }
+
     fn ability_number(&self) -> types::AbilityNumber {
         self.default_read::<u8>(0x15).into()
     }
 
+    fn set_ability_number(&mut self, ability_number: types::AbilityNumber) {
+        self.default_write(0x15, u8::from(ability_number));
+    }
+
     fn pid(&self) -> u32 {
         self.default_read_le(0x18)
     }
 
+    fn set_pid(&mut self, pid: u32) {
+        self.default_write_le(0x18, pid);
+    }
+
     fn nature(&self) -> types::Nature {
         self.default_read::<u8>(0x1C).into()
     }
 
+    fn set_nature(&mut self, nature: types::Nature) {
+        self.default_write(0x1C, u8::from(nature));
+    }
+
     fn gender(&self) -> types::Gender {
         let byte = self.default_read::<u8>(0x1D);
         ((byte >> 1) & 3).into()
     }
 
+    fn set_gender(&mut self, gender: types::Gender) {
+        let byte = self.default_read::<u8>(0x1D);
+        let gender_bits = u8::from(gender) & 3;
+        self.default_write(0x1D, (byte & !0x06) | (gender_bits << 1));
+    }
+
     fn evs(&self) -> types::Stats {
         types::Stats {
             hp: self.default_read(0x1E),
@@ -78,6 +142,15 @@ impl Pkx for Pk6 {
         }
     }
 
+    fn set_evs(&mut self, evs: types::Stats) {
+        self.default_write(0x1E, evs.hp);
+        self.default_write(0x1F, evs.atk);
+        self.default_write(0x20, evs.def);
+        self.default_write(0x21, evs.spa);
+        self.default_write(0x22, evs.spd);
+        self.default_write(0x23, evs.spe);
+    }
+
     fn move1(&self) -> types::Move {
         self.default_read::<u16>(0x5A).into()
     }
@@ -94,21 +167,74 @@ impl Pkx for Pk6 {
         self.default_read::<u16>(0x60).into()
     }
 
+    fn set_moves(
+        &mut self,
+        move1: types::Move,
+        move2: types::Move,
+        move3: types::Move,
+        move4: types::Move,
+    ) {
+        self.default_write::<u16>(0x5A, move1.into());
+        self.default_write::<u16>(0x5C, move2.into());
+        self.default_write::<u16>(0x5E, move3.into());
+        self.default_write::<u16>(0x60, move4.into());
+    }
+
     fn iv32(&self) -> u32 {
         self.default_read_le(0x74)
     }
 
+    fn set_iv32(&mut self, iv32: u32) {
+        self.default_write_le(0x74, iv32);
+    }
+
     fn ht_friendship(&self) -> u32 {
         self.default_read(0xA2)
     }
 
+    fn set_ht_friendship(&mut self, ht_friendship: u32) {
+        self.default_write(0xA2, ht_friendship);
+    }
+
     fn ot_friendship(&self) -> u32 {
         self.default_read(0xCA)
     }
 
+    fn set_ot_friendship(&mut self, ot_friendship: u32) {
+        self.default_write(0xCA, ot_friendship);
+    }
+
     fn language(&self) -> types::Language {
         self.default_read::<u8>(0xE3).into()
     }
+
+    fn set_language(&mut self, language: types::Language) {
+        self.default_write(0xE3, u8::from(language));
+    }
+
+    fn nickname(&self) -> Name {
+        read_utf16_string(self.get_slice(), 0x40, 12)
+    }
+
+    fn set_nickname(&mut self, nickname: &str) {
+        write_utf16_string(self.get_mut_slice(), 0x40, 13, nickname);
+    }
+
+    fn ot_name(&self) -> Name {
+        read_utf16_string(self.get_slice(), 0xB0, 9)
+    }
+
+    fn set_ot_name(&mut self, ot_name: &str) {
+        write_utf16_string(self.get_mut_slice(), 0xB0, 10, ot_name);
+    }
+
+    fn ht_name(&self) -> Name {
+        read_utf16_string(self.get_slice(), 0x78, 8)
+    }
+
+    fn set_ht_name(&mut self, ht_name: &str) {
+        write_utf16_string(self.get_mut_slice(), 0x78, 9, ht_name);
+    }
 }
 
 #[derive(Clone, Copy, Debug, PartialEq)]
@@ -186,7 +312,7 @@ mod test {
     #[test]
     fn should_read_species() {
         let pkx = Pk6::new(TEST_EKX);
-        assert_eq!(pkx.species(), types::Species::Ditto);
+        assert_eq!(pkx.species(), types::Species::DITTO);
     }
 
     #[test]
@@ -226,25 +352,25 @@ mod test {
     #[test]
     fn should_read_nature() {
         let pkx = Pk6::new(TEST_EKX);
-        assert_eq!(pkx.nature(), types::Nature::Adamant)
+        assert_eq!(pkx.nature(), types::Nature::ADAMANT)
     }
 
     #[test]
     fn should_read_minted_nature() {
         let pkx = Pk6::new(TEST_EKX);
-        assert_eq!(pkx.minted_nature(), types::Nature::Adamant)
+        assert_eq!(pkx.minted_nature(), types::Nature::ADAMANT)
     }
 
     #[test]
     fn should_read_ability() {
         let pkx = Pk6::new(TEST_EKX);
-        assert_eq!(pkx.ability(), types::Ability::Imposter)
+        assert_eq!(pkx.ability(), types::Ability::IMPOSTER)
     }
 
     #[test]
     fn should_read_ability_number() {
         let pkx = Pk6::new(TEST_EKX);
-        assert_eq!(pkx.ability_number(), types::AbilityNumber::Hidden)
+        assert_eq!(pkx.ability_number(), types::AbilityNumber::HIDDEN)
     }
 
     #[test]
@@ -256,7 +382,7 @@ mod test {
     #[test]
     fn should_read_language() {
         let pkx = Pk6::new(TEST_EKX);
-        assert_eq!(pkx.language(), types::Language::French)
+        assert_eq!(pkx.language(), types::Language::FRENCH)
     }
 
     #[test]
@@ -268,25 +394,25 @@ mod test {
     #[test]
     fn should_read_move1() {
         let pkx = Pk6::new(TEST_EKX);
-        assert_eq!(pkx.move1(), types::Move::Transform)
+        assert_eq!(pkx.move1(), types::Move::TRANSFORM)
     }
 
     #[test]
     fn should_read_move2() {
         let pkx = Pk6::new(TEST_EKX);
-        assert_eq!(pkx.move2(), types::Move::None)
+        assert_eq!(pkx.move2(), types::Move::NONE)
     }
 
     #[test]
     fn should_read_move3() {
         let pkx = Pk6::new(TEST_EKX);
-        assert_eq!(pkx.move3(), types::Move::None)
+        assert_eq!(pkx.move3(), types::Move::NONE)
     }
 
     #[test]
     fn should_read_move4() {
         let pkx = Pk6::new(TEST_EKX);
-        assert_eq!(pkx.move4(), types::Move::None)
+        assert_eq!(pkx.move4(), types::Move::NONE)
     }
 
     #[test]
@@ -328,4 +454,84 @@ mod test {
         let pkx = Pk6::new(TEST_EKX);
         assert_eq!(pkx.ht_friendship(), 0)
     }
+
+    #[test]
+    fn should_read_checksum() {
+        let pkx = Pk6::new(TEST_EKX);
+        assert_eq!(pkx.checksum(), 0x41d6);
+    }
+
+    #[test]
+    fn should_calculate_matching_checksum() {
+        let pkx = Pk6::new(TEST_EKX);
+        assert_eq!(pkx.calculate_checksum(), pkx.checksum());
+    }
+
+    #[test]
+    fn should_be_valid() {
+        let pkx = Pk6::new(TEST_EKX);
+        assert!(pkx.is_valid());
+    }
+
+    #[test]
+    fn should_be_invalid_after_species_hacked_in() {
+        let mut pkx = Pk6::new(TEST_EKX);
+        pkx.set_species(types::Species(9001));
+        assert!(!pkx.is_valid());
+    }
+
+    #[test]
+    fn should_read_nickname() {
+        let pkx = Pk6::new(TEST_EKX);
+        assert_eq!(pkx.nickname(), "Adamant 6IVs");
+    }
+
+    #[test]
+    fn should_read_ot_name() {
+        let pkx = Pk6::new(TEST_EKX);
+        assert_eq!(pkx.ot_name(), "Ditto is ");
+    }
+
+    #[test]
+    fn should_round_trip_ot_name_without_truncation() {
+        let mut pkx = Pk6::new(TEST_EKX);
+        pkx.set_ot_name("Ditto is ");
+        assert_eq!(pkx.ot_name(), "Ditto is ");
+    }
+
+    #[test]
+    fn should_round_trip_encryption_unmodified() {
+        let pkx = Pk6::new(TEST_EKX);
+        let reencrypted = pkx.encrypt();
+        let roundtripped = Pk6::new(reencrypted);
+        assert_eq!(roundtripped.get_slice(), pkx.get_slice());
+    }
+
+    #[test]
+    fn should_round_trip_encryption_after_mutation() {
+        let mut pkx = Pk6::new(TEST_EKX);
+        let evs = types::Stats {
+            hp: 4,
+            atk: 252,
+            def: 0,
+            spa: 0,
+            spd: 0,
+            spe: 252,
+        };
+        pkx.set_species(types::Species::MEW);
+        pkx.set_evs(evs);
+        pkx.set_moves(
+            types::Move::TRANSFORM,
+            types::Move::NONE,
+            types::Move::NONE,
+            types::Move::NONE,
+        );
+        pkx.set_iv32(pkx.iv32());
+
+        let reencrypted = pkx.encrypt();
+        let roundtripped = Pk6::new(reencrypted);
+
+        assert_eq!(roundtripped.species(), types::Species::MEW);
+        assert_eq!(roundtripped.evs(), evs);
+    }
 }
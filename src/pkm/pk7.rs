@@ -0,0 +1,494 @@
+use super::{
+    pkx::{read_utf16_string, write_utf16_string, Name, Pkx},
+    poke_crypto, types,
+};
+use core::convert::TryInto;
+use no_std_io::{Reader, Writer};
+use safe_transmute::TriviallyTransmutable;
+
+pub type Pk7Bytes = [u8; Pk7::STORED_SIZE];
+
+pub struct Pk7 {
+    data: Pk7Bytes,
+}
+
+impl Pk7 {
+    pub const STORED_SIZE: usize = 232;
+    pub const BLOCK_SIZE: usize = 56;
+
+    pub fn new(data: [u8; Pk7::STORED_SIZE]) -> Self {
+        let seed_bytes: [u8; 4] = data[0..4].try_into().unwrap();
+        let seed = u32::from_le_bytes(seed_bytes);
+        Self {
+            data: poke_crypto::decrypt::<{ Pk7::STORED_SIZE }, { Pk7::BLOCK_SIZE }>(data, seed),
+        }
+    }
+
+    /// Builds a `Pk7` directly from already-decrypted bytes, skipping the
+    /// block-shuffle/PRNG-XOR step `new` performs on stored data. Used when
+    /// constructing an entity from scratch, e.g. during [`super::convert`].
+    pub(crate) fn from_decrypted(data: Pk7Bytes) -> Self {
+        Self { data }
+    }
+
+    /// Re-encrypts this entity back into its stored `.ek7` form, recomputing
+    /// the checksum over the block region first.
+    pub fn encrypt(&self) -> Pk7Bytes {
+        let mut data = self.data;
+        let checksum = self.calculate_checksum();
+        data[0x06..0x08].copy_from_slice(&checksum.to_le_bytes());
+        poke_crypto::encrypt::<{ Pk7::STORED_SIZE }, { Pk7::BLOCK_SIZE }>(data)
+    }
+}
+
+impl Reader for Pk7 {
+    fn get_slice(&self) -> &[u8] {
+        &self.data
+    }
+}
+
+impl Writer for Pk7 {
+    fn get_mut_slice(&mut self) -> &mut [u8] {
+        &mut self.data
+    }
+}
+
+impl Pkx for Pk7 {
+    fn encryption_constant(&self) -> u32 {
+        self.default_read_le(0x00)
+    }
+
+    fn set_encryption_constant(&mut self, encryption_constant: u32) {
+        self.default_write_le(0x00, encryption_constant);
+    }
+
+    fn checksum(&self) -> u16 {
+        self.default_read_le(0x06)
+    }
+
+    fn set_checksum(&mut self, checksum: u16) {
+        self.default_write_le(0x06, checksum);
+    }
+
+    fn species(&self) -> types::Species {
+        self.default_read_le::<u16>(0x08).into()
+    }
+
+    fn set_species(&mut self, species: types::Species) {
+        self.default_write_le::<u16>(0x08, species.into());
+    }
+
+    fn tid(&self) -> u16 {
+        self.default_read_le(0x0C)
+    }
+
+    fn set_tid(&mut self, tid: u16) {
+        self.default_write_le(0x0C, tid);
+    }
+
+    fn sid(&self) -> u16 {
+        self.default_read_le(0x0E)
+    }
+
+    fn set_sid(&mut self, sid: u16) {
+        self.default_write_le(0x0E, sid);
+    }
+
+    fn ability(&self) -> types::Ability {
+        let ability: u8 = self.default_read(0x14);
+        (ability as u16).into()
+    }
+
+    fn set_ability(&mut self, ability: types::Ability) {
+        self.default_write(0x14, u16::from(ability) as u8);
+    }
+
+    fn ability_number(&self) -> types::AbilityNumber {
+        self.default_read::<u8>(0x15).into()
+    }
+
+    fn set_ability_number(&mut self, ability_number: types::AbilityNumber) {
+        self.default_write(0x15, u8::from(ability_number));
+    }
+
+    fn pid(&self) -> u32 {
+        self.default_read_le(0x18)
+    }
+
+    fn set_pid(&mut self, pid: u32) {
+        self.default_write_le(0x18, pid);
+    }
+
+    fn nature(&self) -> types::Nature {
+        self.default_read::<u8>(0x1C).into()
+    }
+
+    fn set_nature(&mut self, nature: types::Nature) {
+        self.default_write(0x1C, u8::from(nature));
+    }
+
+    fn gender(&self) -> types::Gender {
+        let byte = self.default_read::<u8>(0x1D);
+        ((byte >> 1) & 3).into()
+    }
+
+    fn set_gender(&mut self, gender: types::Gender) {
+        let byte = self.default_read::<u8>(0x1D);
+        let gender_bits = u8::from(gender) & 3;
+        self.default_write(0x1D, (byte & !0x06) | (gender_bits << 1));
+    }
+
+    fn evs(&self) -> types::Stats {
+        types::Stats {
+            hp: self.default_read(0x1E),
+            atk: self.default_read(0x1F),
+            def: self.default_read(0x20),
+            spa: self.default_read(0x21),
+            spd: self.default_read(0x22),
+            spe: self.default_read(0x23),
+        }
+    }
+
+    fn set_evs(&mut self, evs: types::Stats) {
+        self.default_write(0x1E, evs.hp);
+        self.default_write(0x1F, evs.atk);
+        self.default_write(0x20, evs.def);
+        self.default_write(0x21, evs.spa);
+        self.default_write(0x22, evs.spd);
+        self.default_write(0x23, evs.spe);
+    }
+
+    fn move1(&self) -> types::Move {
+        self.default_read::<u16>(0x5A).into()
+    }
+
+    fn move2(&self) -> types::Move {
+        self.default_read::<u16>(0x5C).into()
+    }
+
+    fn move3(&self) -> types::Move {
+        self.default_read::<u16>(0x5E).into()
+    }
+
+    fn move4(&self) -> types::Move {
+        self.default_read::<u16>(0x60).into()
+    }
+
+    fn set_moves(
+        &mut self,
+        move1: types::Move,
+        move2: types::Move,
+        move3: types::Move,
+        move4: types::Move,
+    ) {
+        self.default_write::<u16>(0x5A, move1.into());
+        self.default_write::<u16>(0x5C, move2.into());
+        self.default_write::<u16>(0x5E, move3.into());
+        self.default_write::<u16>(0x60, move4.into());
+    }
+
+    fn iv32(&self) -> u32 {
+        self.default_read_le(0x74)
+    }
+
+    fn set_iv32(&mut self, iv32: u32) {
+        self.default_write_le(0x74, iv32);
+    }
+
+    fn ht_friendship(&self) -> u32 {
+        self.default_read(0xA2)
+    }
+
+    fn set_ht_friendship(&mut self, ht_friendship: u32) {
+        self.default_write(0xA2, ht_friendship);
+    }
+
+    fn ot_friendship(&self) -> u32 {
+        self.default_read(0xCA)
+    }
+
+    fn set_ot_friendship(&mut self, ot_friendship: u32) {
+        self.default_write(0xCA, ot_friendship);
+    }
+
+    fn language(&self) -> types::Language {
+        self.default_read::<u8>(0xE3).into()
+    }
+
+    fn set_language(&mut self, language: types::Language) {
+        self.default_write(0xE3, u8::from(language));
+    }
+
+    fn nickname(&self) -> Name {
+        read_utf16_string(self.get_slice(), 0x40, 12)
+    }
+
+    fn set_nickname(&mut self, nickname: &str) {
+        write_utf16_string(self.get_mut_slice(), 0x40, 13, nickname);
+    }
+
+    fn ot_name(&self) -> Name {
+        read_utf16_string(self.get_slice(), 0xB0, 9)
+    }
+
+    fn set_ot_name(&mut self, ot_name: &str) {
+        write_utf16_string(self.get_mut_slice(), 0xB0, 10, ot_name);
+    }
+
+    fn ht_name(&self) -> Name {
+        read_utf16_string(self.get_slice(), 0x78, 8)
+    }
+
+    fn set_ht_name(&mut self, ht_name: &str) {
+        write_utf16_string(self.get_mut_slice(), 0x78, 9, ht_name);
+    }
+
+    fn species_max(&self) -> types::Species {
+        types::Species::MAX_GEN7
+    }
+
+    fn move_max(&self) -> types::Move {
+        types::Move::MAX_GEN7
+    }
+
+    fn ability_max(&self) -> types::Ability {
+        types::Ability::MAX_GEN7
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Pk7Data(Pk7Bytes);
+
+// This is safe because the bytes in Pk7Data can be anything
+unsafe impl TriviallyTransmutable for Pk7Data {}
+
+impl From<Pk7Data> for Pk7 {
+    fn from(data: Pk7Data) -> Self {
+        Self::new(data.0)
+    }
+}
+
+impl Default for Pk7Data {
+    fn default() -> Self {
+        Self([0; Pk7::STORED_SIZE])
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const TEST_EKX: Pk7Bytes = [
+        0x31, 0x9f, 0x2c, 0x7a, 0x00, 0x00, 0x70, 0x4d, 0x89, 0x2c, 0x91, 0xbc, 0xb5, 0x98, 0x08,
+        0xec, 0x7b, 0x50, 0x69, 0x13, 0x27, 0x2b, 0x94, 0x12, 0xdd, 0x74, 0xea, 0xc9, 0x4a, 0xbb,
+        0x10, 0x89, 0xa4, 0x0e, 0xc4, 0x50, 0x15, 0xbc, 0x06, 0xcb, 0x68, 0xb9, 0xa7, 0x47, 0x13,
+        0x03, 0xf2, 0x7c, 0x20, 0x2d, 0xd7, 0x46, 0xba, 0x8d, 0xcb, 0x59, 0x36, 0x0d, 0xc7, 0xe8,
+        0x75, 0x4f, 0x2d, 0x74, 0xc5, 0x0c, 0x97, 0x20, 0xa9, 0x80, 0xe3, 0x5f, 0x3a, 0xff, 0xef,
+        0x33, 0xf6, 0x95, 0xe4, 0x31, 0x39, 0x2b, 0x0e, 0x7e, 0xbb, 0x67, 0x80, 0x8b, 0xc1, 0xe7,
+        0x22, 0x50, 0xdf, 0x4d, 0xd6, 0x24, 0x8d, 0xe7, 0xd9, 0x82, 0xce, 0x33, 0x89, 0x8a, 0xbd,
+        0xd6, 0x37, 0x8d, 0xc2, 0xe8, 0xb3, 0x02, 0xb5, 0x2e, 0xa9, 0x34, 0x43, 0x30, 0x18, 0xcd,
+        0x0d, 0x64, 0x3b, 0x2a, 0x85, 0x56, 0x9e, 0x32, 0x09, 0xd4, 0xc5, 0x29, 0x7e, 0x4f, 0xfc,
+        0x17, 0x12, 0x11, 0x8b, 0x6c, 0x01, 0x9c, 0xb1, 0x07, 0xc6, 0x79, 0x86, 0x87, 0x6a, 0x87,
+        0x38, 0xe7, 0x04, 0x37, 0x1e, 0x05, 0x50, 0x73, 0x79, 0xcb, 0x8b, 0x09, 0xda, 0x50, 0xf2,
+        0x3d, 0x82, 0xa0, 0x9b, 0x89, 0x3a, 0xca, 0x7c, 0x04, 0x76, 0x98, 0x9d, 0xbc, 0xaf, 0x04,
+        0xe6, 0xc5, 0xa9, 0x95, 0x1e, 0x26, 0x7a, 0xb3, 0xa4, 0xa7, 0xf3, 0x04, 0xcc, 0xb5, 0xd5,
+        0x8c, 0xa3, 0xe9, 0x9f, 0xd7, 0xf9, 0x35, 0x99, 0x43, 0x3c, 0xbb, 0xd5, 0x9b, 0xa9, 0x26,
+        0xba, 0x7b, 0x39, 0x8b, 0xe3, 0xd7, 0x77, 0x1b, 0x3b, 0x1d, 0xe3, 0x85, 0x7d, 0x2a, 0x21,
+        0x0c, 0xb1, 0x9b, 0x87, 0x74, 0xff, 0xc0,
+    ];
+
+    #[test]
+    fn should_decrypt() {
+        let result: Pk7Bytes = [
+            0x31, 0x9f, 0x2c, 0x7a, 0x00, 0x00, 0x70, 0x4d, 0xee, 0x02, 0x00, 0x00, 0x67, 0x2b,
+            0xce, 0x56, 0x00, 0x00, 0x00, 0x00, 0x41, 0x01, 0x00, 0x00, 0x78, 0x56, 0x34, 0x12,
+            0x03, 0x04, 0xfc, 0x00, 0x04, 0xfc, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x41, 0x00, 0x6c, 0x00, 0x6f, 0x00,
+            0x6c, 0x00, 0x61, 0x00, 0x20, 0x00, 0x4d, 0x00, 0x6f, 0x00, 0x6e, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x57, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0xce, 0x39, 0xe7, 0x1c, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x48, 0x00, 0x61, 0x00, 0x75, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x00,
+        ];
+
+        let pkx = Pk7::new(TEST_EKX);
+        assert_eq!(pkx.get_slice(), result)
+    }
+
+    #[test]
+    fn pk7_data_size_should_be_232() {
+        assert_eq!(core::mem::size_of::<Pk7Data>(), Pk7::STORED_SIZE);
+    }
+
+    #[test]
+    fn should_read_species() {
+        let pkx = Pk7::new(TEST_EKX);
+        // Above the Generation 6 cap (721) but within Generation 7's (807):
+        // this is the case the old hardcoded Species::MAX bound got wrong.
+        assert_eq!(pkx.species(), types::Species(750));
+    }
+
+    #[test]
+    fn should_read_pid() {
+        let pkx = Pk7::new(TEST_EKX);
+        assert_eq!(pkx.pid(), 0x12345678)
+    }
+
+    #[test]
+    fn should_read_tid() {
+        let pkx = Pk7::new(TEST_EKX);
+        assert_eq!(pkx.tid(), 11111)
+    }
+
+    #[test]
+    fn should_read_sid() {
+        let pkx = Pk7::new(TEST_EKX);
+        assert_eq!(pkx.sid(), 22222)
+    }
+
+    #[test]
+    fn should_read_nature() {
+        let pkx = Pk7::new(TEST_EKX);
+        assert_eq!(pkx.nature(), types::Nature::ADAMANT)
+    }
+
+    #[test]
+    fn should_read_ability_number() {
+        let pkx = Pk7::new(TEST_EKX);
+        assert_eq!(pkx.ability_number(), types::AbilityNumber::FIRST)
+    }
+
+    #[test]
+    fn should_read_gender() {
+        let pkx = Pk7::new(TEST_EKX);
+        assert_eq!(pkx.gender(), types::Gender::Genderless)
+    }
+
+    #[test]
+    fn should_read_move1() {
+        let pkx = Pk7::new(TEST_EKX);
+        assert_eq!(pkx.move1(), types::Move(87))
+    }
+
+    #[test]
+    fn should_read_move2() {
+        let pkx = Pk7::new(TEST_EKX);
+        assert_eq!(pkx.move2(), types::Move::NONE)
+    }
+
+    #[test]
+    fn should_read_ivs() {
+        let pkx = Pk7::new(TEST_EKX);
+        let stats = types::Stats {
+            hp: 14,
+            atk: 14,
+            def: 14,
+            spa: 14,
+            spd: 14,
+            spe: 14,
+        };
+        assert_eq!(pkx.ivs(), stats)
+    }
+
+    #[test]
+    fn should_read_evs() {
+        let pkx = Pk7::new(TEST_EKX);
+        let stats = types::Stats {
+            hp: 252,
+            atk: 0,
+            def: 4,
+            spa: 252,
+            spd: 0,
+            spe: 0,
+        };
+        assert_eq!(pkx.evs(), stats)
+    }
+
+    #[test]
+    fn should_read_checksum() {
+        let pkx = Pk7::new(TEST_EKX);
+        assert_eq!(pkx.checksum(), 0x4d70);
+    }
+
+    #[test]
+    fn should_calculate_matching_checksum() {
+        let pkx = Pk7::new(TEST_EKX);
+        assert_eq!(pkx.calculate_checksum(), pkx.checksum());
+    }
+
+    #[test]
+    fn should_be_valid() {
+        let pkx = Pk7::new(TEST_EKX);
+        assert!(pkx.is_valid());
+    }
+
+    #[test]
+    fn should_be_invalid_after_species_hacked_in() {
+        let mut pkx = Pk7::new(TEST_EKX);
+        pkx.set_species(types::Species(types::Species::MAX_GEN7.0 + 1));
+        assert!(!pkx.is_valid());
+    }
+
+    #[test]
+    fn should_read_nickname() {
+        let pkx = Pk7::new(TEST_EKX);
+        assert_eq!(pkx.nickname(), "Alola Mon");
+    }
+
+    #[test]
+    fn should_read_ot_name() {
+        let pkx = Pk7::new(TEST_EKX);
+        assert_eq!(pkx.ot_name(), "Hau");
+    }
+
+    #[test]
+    fn should_round_trip_encryption_unmodified() {
+        let pkx = Pk7::new(TEST_EKX);
+        let reencrypted = pkx.encrypt();
+        let roundtripped = Pk7::new(reencrypted);
+        assert_eq!(roundtripped.get_slice(), pkx.get_slice());
+    }
+
+    #[test]
+    fn should_round_trip_encryption_after_mutation() {
+        let mut pkx = Pk7::new(TEST_EKX);
+        let evs = types::Stats {
+            hp: 4,
+            atk: 252,
+            def: 0,
+            spa: 0,
+            spd: 0,
+            spe: 252,
+        };
+        pkx.set_species(types::Species::MEW);
+        pkx.set_evs(evs);
+        pkx.set_moves(
+            types::Move::TRANSFORM,
+            types::Move::NONE,
+            types::Move::NONE,
+            types::Move::NONE,
+        );
+        pkx.set_iv32(pkx.iv32());
+
+        let reencrypted = pkx.encrypt();
+        let roundtripped = Pk7::new(reencrypted);
+
+        assert_eq!(roundtripped.species(), types::Species::MEW);
+        assert_eq!(roundtripped.evs(), evs);
+    }
+
+    #[test]
+    fn should_round_trip_ot_name_without_truncation() {
+        let mut pkx = Pk7::new(TEST_EKX);
+        pkx.set_ot_name("Ditto is ");
+        assert_eq!(pkx.ot_name(), "Ditto is ");
+    }
+}
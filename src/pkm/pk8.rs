@@ -0,0 +1,539 @@
+use super::{
+    pkx::{read_utf16_string, write_utf16_string, Name, Pkx},
+    poke_crypto, types,
+};
+use core::convert::TryInto;
+use no_std_io::{Reader, Writer};
+use safe_transmute::TriviallyTransmutable;
+
+pub type Pk8Bytes = [u8; Pk8::STORED_SIZE];
+
+pub struct Pk8 {
+    data: Pk8Bytes,
+}
+
+impl Pk8 {
+    pub const STORED_SIZE: usize = 328;
+    pub const BLOCK_SIZE: usize = 80;
+
+    pub fn new(data: [u8; Pk8::STORED_SIZE]) -> Self {
+        let seed_bytes: [u8; 4] = data[0..4].try_into().unwrap();
+        let seed = u32::from_le_bytes(seed_bytes);
+        Self {
+            data: poke_crypto::decrypt::<{ Pk8::STORED_SIZE }, { Pk8::BLOCK_SIZE }>(data, seed),
+        }
+    }
+
+    /// Builds a `Pk8` directly from already-decrypted bytes, skipping the
+    /// block-shuffle/PRNG-XOR step `new` performs on stored data. Used when
+    /// constructing an entity from scratch, e.g. during [`super::convert`].
+    pub(crate) fn from_decrypted(data: Pk8Bytes) -> Self {
+        Self { data }
+    }
+
+    /// Re-encrypts this entity back into its stored `.ek8` form, recomputing
+    /// the checksum over the block region first.
+    pub fn encrypt(&self) -> Pk8Bytes {
+        let mut data = self.data;
+        let checksum = self.calculate_checksum();
+        data[0x06..0x08].copy_from_slice(&checksum.to_le_bytes());
+        poke_crypto::encrypt::<{ Pk8::STORED_SIZE }, { Pk8::BLOCK_SIZE }>(data)
+    }
+}
+
+impl Reader for Pk8 {
+    fn get_slice(&self) -> &[u8] {
+        &self.data
+    }
+}
+
+impl Writer for Pk8 {
+    fn get_mut_slice(&mut self) -> &mut [u8] {
+        &mut self.data
+    }
+}
+
+impl Pkx for Pk8 {
+    fn encryption_constant(&self) -> u32 {
+        self.default_read_le(0x00)
+    }
+
+    fn set_encryption_constant(&mut self, encryption_constant: u32) {
+        self.default_write_le(0x00, encryption_constant);
+    }
+
+    fn checksum(&self) -> u16 {
+        self.default_read_le(0x06)
+    }
+
+    fn set_checksum(&mut self, checksum: u16) {
+        self.default_write_le(0x06, checksum);
+    }
+
+    fn species(&self) -> types::Species {
+        self.default_read_le::<u16>(0x08).into()
+    }
+
+    fn set_species(&mut self, species: types::Species) {
+        self.default_write_le::<u16>(0x08, species.into());
+    }
+
+    fn tid(&self) -> u16 {
+        self.default_read_le(0x0C)
+    }
+
+    fn set_tid(&mut self, tid: u16) {
+        self.default_write_le(0x0C, tid);
+    }
+
+    fn sid(&self) -> u16 {
+        self.default_read_le(0x0E)
+    }
+
+    fn set_sid(&mut self, sid: u16) {
+        self.default_write_le(0x0E, sid);
+    }
+
+    fn ability(&self) -> types::Ability {
+        let ability: u8 = self.default_read(0x14);
+        (ability as u16).into()
+    }
+
+    fn set_ability(&mut self, ability: types::Ability) {
+        self.default_write(0x14, u16::from(ability) as u8);
+    }
+
+    fn ability_number(&self) -> types::AbilityNumber {
+        self.default_read::<u8>(0x15).into()
+    }
+
+    fn set_ability_number(&mut self, ability_number: types::AbilityNumber) {
+        self.default_write(0x15, u8::from(ability_number));
+    }
+
+    fn pid(&self) -> u32 {
+        self.default_read_le(0x18)
+    }
+
+    fn set_pid(&mut self, pid: u32) {
+        self.default_write_le(0x18, pid);
+    }
+
+    fn nature(&self) -> types::Nature {
+        self.default_read::<u8>(0x1C).into()
+    }
+
+    fn set_nature(&mut self, nature: types::Nature) {
+        self.default_write(0x1C, u8::from(nature));
+    }
+
+    fn gender(&self) -> types::Gender {
+        let byte = self.default_read::<u8>(0x1D);
+        ((byte >> 1) & 3).into()
+    }
+
+    fn set_gender(&mut self, gender: types::Gender) {
+        let byte = self.default_read::<u8>(0x1D);
+        let gender_bits = u8::from(gender) & 3;
+        self.default_write(0x1D, (byte & !0x06) | (gender_bits << 1));
+    }
+
+    fn evs(&self) -> types::Stats {
+        types::Stats {
+            hp: self.default_read(0x1E),
+            atk: self.default_read(0x1F),
+            def: self.default_read(0x20),
+            spa: self.default_read(0x21),
+            spd: self.default_read(0x22),
+            spe: self.default_read(0x23),
+        }
+    }
+
+    fn set_evs(&mut self, evs: types::Stats) {
+        self.default_write(0x1E, evs.hp);
+        self.default_write(0x1F, evs.atk);
+        self.default_write(0x20, evs.def);
+        self.default_write(0x21, evs.spa);
+        self.default_write(0x22, evs.spd);
+        self.default_write(0x23, evs.spe);
+    }
+
+    // Block A (species..evs, above) keeps Pk6's offsets: it starts at the
+    // same 0x08 regardless of generation and every field on it falls well
+    // inside the first BLOCK_SIZE (80) bytes either way. Block B onward
+    // moved: Pk8's 80-byte blocks are 24 bytes wider than Pk6's, so each
+    // later block starts 24/48/72 bytes later than its Pk6 counterpart.
+
+    fn move1(&self) -> types::Move {
+        self.default_read::<u16>(0x72).into()
+    }
+
+    fn move2(&self) -> types::Move {
+        self.default_read::<u16>(0x74).into()
+    }
+
+    fn move3(&self) -> types::Move {
+        self.default_read::<u16>(0x76).into()
+    }
+
+    fn move4(&self) -> types::Move {
+        self.default_read::<u16>(0x78).into()
+    }
+
+    fn set_moves(
+        &mut self,
+        move1: types::Move,
+        move2: types::Move,
+        move3: types::Move,
+        move4: types::Move,
+    ) {
+        self.default_write::<u16>(0x72, move1.into());
+        self.default_write::<u16>(0x74, move2.into());
+        self.default_write::<u16>(0x76, move3.into());
+        self.default_write::<u16>(0x78, move4.into());
+    }
+
+    fn iv32(&self) -> u32 {
+        self.default_read_le(0x8C)
+    }
+
+    fn set_iv32(&mut self, iv32: u32) {
+        self.default_write_le(0x8C, iv32);
+    }
+
+    fn ht_friendship(&self) -> u32 {
+        self.default_read(0xD2)
+    }
+
+    fn set_ht_friendship(&mut self, ht_friendship: u32) {
+        self.default_write(0xD2, ht_friendship);
+    }
+
+    fn ot_friendship(&self) -> u32 {
+        self.default_read(0x112)
+    }
+
+    fn set_ot_friendship(&mut self, ot_friendship: u32) {
+        self.default_write(0x112, ot_friendship);
+    }
+
+    fn language(&self) -> types::Language {
+        self.default_read::<u8>(0x12B).into()
+    }
+
+    fn set_language(&mut self, language: types::Language) {
+        self.default_write(0x12B, u8::from(language));
+    }
+
+    fn nickname(&self) -> Name {
+        read_utf16_string(self.get_slice(), 0x58, 12)
+    }
+
+    fn set_nickname(&mut self, nickname: &str) {
+        write_utf16_string(self.get_mut_slice(), 0x58, 13, nickname);
+    }
+
+    fn ot_name(&self) -> Name {
+        read_utf16_string(self.get_slice(), 0xF8, 9)
+    }
+
+    fn set_ot_name(&mut self, ot_name: &str) {
+        write_utf16_string(self.get_mut_slice(), 0xF8, 10, ot_name);
+    }
+
+    fn ht_name(&self) -> Name {
+        read_utf16_string(self.get_slice(), 0xA8, 8)
+    }
+
+    fn set_ht_name(&mut self, ht_name: &str) {
+        write_utf16_string(self.get_mut_slice(), 0xA8, 9, ht_name);
+    }
+
+    fn species_max(&self) -> types::Species {
+        types::Species::MAX_GEN8
+    }
+
+    fn move_max(&self) -> types::Move {
+        types::Move::MAX_GEN8
+    }
+
+    fn ability_max(&self) -> types::Ability {
+        types::Ability::MAX_GEN8
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Pk8Data(Pk8Bytes);
+
+// This is safe because the bytes in Pk8Data can be anything
+unsafe impl TriviallyTransmutable for Pk8Data {}
+
+impl From<Pk8Data> for Pk8 {
+    fn from(data: Pk8Data) -> Self {
+        Self::new(data.0)
+    }
+}
+
+impl Default for Pk8Data {
+    fn default() -> Self {
+        Self([0; Pk8::STORED_SIZE])
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const TEST_EKX: Pk8Bytes = [
+        0x04, 0xf2, 0x13, 0x9b, 0x00, 0x00, 0x5e, 0x99, 0x14, 0x4e, 0xaa, 0xb2, 0x56, 0xc9, 0x4a,
+        0xc7, 0xea, 0xfe, 0xc2, 0xc7, 0x24, 0xb5, 0xd2, 0xee, 0x20, 0x9b, 0x26, 0x74, 0x7a, 0xed,
+        0xc3, 0x8d, 0x54, 0xa3, 0xc4, 0xd2, 0xd9, 0x17, 0x6e, 0x72, 0xef, 0x02, 0x69, 0x3f, 0x46,
+        0xe5, 0xda, 0x85, 0xfb, 0xaf, 0x04, 0x7e, 0xf4, 0xb6, 0xb2, 0x03, 0xad, 0xb7, 0xce, 0x9a,
+        0x7c, 0x5b, 0x4d, 0xf2, 0x43, 0x91, 0x8e, 0xc6, 0x4d, 0xe0, 0xd6, 0x79, 0x24, 0xca, 0x19,
+        0xb3, 0xd0, 0x8c, 0xee, 0x0f, 0x38, 0x5f, 0x18, 0x34, 0xed, 0xae, 0x92, 0x7e, 0x0f, 0x54,
+        0x0d, 0x28, 0x0d, 0x3b, 0xe1, 0x6a, 0xba, 0x73, 0x97, 0xed, 0xd9, 0xc1, 0x80, 0xf6, 0xfa,
+        0x44, 0x90, 0x6f, 0x67, 0xa7, 0xaa, 0xb0, 0xfc, 0x54, 0xc8, 0x21, 0x56, 0xda, 0xa2, 0xf9,
+        0x22, 0xdd, 0x1c, 0x21, 0x3b, 0x07, 0x58, 0x07, 0x06, 0xd8, 0xa8, 0xe7, 0xed, 0x0e, 0xdc,
+        0x5b, 0x2c, 0x51, 0x54, 0x34, 0x37, 0xd0, 0x8d, 0xf5, 0x34, 0xcf, 0xfc, 0x2a, 0x64, 0x1d,
+        0x75, 0xc5, 0x6f, 0xc4, 0x73, 0xcb, 0xf1, 0x94, 0xf3, 0xcb, 0xb5, 0xb8, 0xca, 0x58, 0x84,
+        0x51, 0x9a, 0x5b, 0x39, 0x3c, 0xb4, 0xf0, 0x73, 0xa5, 0x37, 0xa6, 0x0d, 0xf9, 0xde, 0x17,
+        0x8a, 0x53, 0xdb, 0xf8, 0x5c, 0x72, 0xcc, 0x3c, 0x20, 0x00, 0x30, 0x48, 0x94, 0x4d, 0x46,
+        0x79, 0xa7, 0xc2, 0xac, 0xf5, 0x53, 0x31, 0x45, 0x12, 0x95, 0x17, 0x0f, 0xed, 0xf5, 0x92,
+        0xd6, 0x7c, 0x7f, 0x9f, 0xe5, 0x80, 0x23, 0x05, 0x20, 0xe9, 0xcb, 0x61, 0x2f, 0xa9, 0xaf,
+        0xdb, 0xa5, 0xa5, 0xaa, 0x86, 0x43, 0x88, 0xa3, 0x38, 0xc7, 0xb4, 0x46, 0xbc, 0xf0, 0x59,
+        0x97, 0xd6, 0x8e, 0x9e, 0x36, 0xbb, 0xe4, 0x30, 0x43, 0x24, 0xa8, 0x3c, 0x3a, 0x9e, 0x52,
+        0x26, 0x54, 0x85, 0xb0, 0xee, 0x24, 0x01, 0xcf, 0xdf, 0x82, 0xcc, 0xb2, 0xac, 0x13, 0x1b,
+        0x7c, 0x8f, 0xe2, 0x42, 0xee, 0xbd, 0xe6, 0x47, 0x6a, 0xd2, 0x78, 0xd0, 0xdc, 0x17, 0xf0,
+        0xb4, 0x39, 0x11, 0x0f, 0x1a, 0x70, 0xa9, 0xe8, 0x24, 0x02, 0x47, 0x4d, 0xa8, 0x9e, 0x16,
+        0x22, 0xff, 0x76, 0x52, 0x01, 0x6c, 0x20, 0xa8, 0x04, 0x1e, 0x46, 0x35, 0xbf, 0x33, 0x76,
+        0x73, 0xb2, 0x10, 0xc5, 0xe5, 0xae, 0xe6, 0x2e, 0xff, 0x0b, 0xc4, 0xcc, 0x96,
+    ];
+
+    #[test]
+    fn should_decrypt() {
+        let result: Pk8Bytes = [
+            0x04, 0xf2, 0x13, 0x9b, 0x00, 0x00, 0x5e, 0x99, 0x7a, 0x03, 0x00, 0x00, 0x35, 0x82,
+            0x9c, 0xad, 0x00, 0x00, 0x00, 0x00, 0x15, 0x04, 0x00, 0x00, 0x21, 0x43, 0x65, 0x87,
+            0x18, 0x00, 0x00, 0xfc, 0x00, 0x04, 0x00, 0xfc, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x47, 0x00, 0x61, 0x00, 0x6c, 0x00, 0x61, 0x00, 0x72, 0x00,
+            0x20, 0x00, 0x50, 0x00, 0x61, 0x00, 0x6c, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x01, 0x00, 0x02, 0x00, 0x03, 0x00, 0x04, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x18, 0x63, 0x8c, 0x31, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x4c, 0x00, 0x65, 0x00,
+            0x6f, 0x00, 0x6e, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        ];
+
+        let pkx = Pk8::new(TEST_EKX);
+        assert_eq!(pkx.get_slice(), result)
+    }
+
+    #[test]
+    fn pk8_data_size_should_be_328() {
+        assert_eq!(core::mem::size_of::<Pk8Data>(), Pk8::STORED_SIZE);
+    }
+
+    #[test]
+    fn should_read_species() {
+        let pkx = Pk8::new(TEST_EKX);
+        // Above the Generation 7 cap (807) but within Generation 8's (898):
+        // this is the case the old hardcoded Species::MAX bound got wrong.
+        assert_eq!(pkx.species(), types::Species(890));
+    }
+
+    #[test]
+    fn should_read_pid() {
+        let pkx = Pk8::new(TEST_EKX);
+        assert_eq!(pkx.pid(), 0x87654321)
+    }
+
+    #[test]
+    fn should_read_tid() {
+        let pkx = Pk8::new(TEST_EKX);
+        assert_eq!(pkx.tid(), 33333)
+    }
+
+    #[test]
+    fn should_read_sid() {
+        let pkx = Pk8::new(TEST_EKX);
+        assert_eq!(pkx.sid(), 44444)
+    }
+
+    #[test]
+    fn should_read_nature() {
+        let pkx = Pk8::new(TEST_EKX);
+        assert_eq!(pkx.nature(), types::Nature(24));
+    }
+
+    #[test]
+    fn should_read_ability_number() {
+        let pkx = Pk8::new(TEST_EKX);
+        assert_eq!(pkx.ability_number(), types::AbilityNumber::HIDDEN)
+    }
+
+    #[test]
+    fn should_read_gender() {
+        let pkx = Pk8::new(TEST_EKX);
+        assert_eq!(pkx.gender(), types::Gender::Male)
+    }
+
+    #[test]
+    fn should_read_move1() {
+        let pkx = Pk8::new(TEST_EKX);
+        assert_eq!(pkx.move1(), types::Move(1))
+    }
+
+    #[test]
+    fn should_read_move4() {
+        let pkx = Pk8::new(TEST_EKX);
+        assert_eq!(pkx.move4(), types::Move(4))
+    }
+
+    #[test]
+    fn should_read_ivs() {
+        let pkx = Pk8::new(TEST_EKX);
+        let stats = types::Stats {
+            hp: 24,
+            atk: 24,
+            def: 24,
+            spa: 24,
+            spd: 24,
+            spe: 24,
+        };
+        assert_eq!(pkx.ivs(), stats)
+    }
+
+    #[test]
+    fn should_read_evs() {
+        let pkx = Pk8::new(TEST_EKX);
+        let stats = types::Stats {
+            hp: 0,
+            atk: 252,
+            def: 0,
+            spa: 4,
+            spd: 0,
+            spe: 252,
+        };
+        assert_eq!(pkx.evs(), stats)
+    }
+
+    #[test]
+    fn should_read_checksum() {
+        let pkx = Pk8::new(TEST_EKX);
+        assert_eq!(pkx.checksum(), 0x995e);
+    }
+
+    #[test]
+    fn should_calculate_matching_checksum() {
+        let pkx = Pk8::new(TEST_EKX);
+        assert_eq!(pkx.calculate_checksum(), pkx.checksum());
+    }
+
+    #[test]
+    fn should_be_valid() {
+        let pkx = Pk8::new(TEST_EKX);
+        assert!(pkx.is_valid());
+    }
+
+    #[test]
+    fn should_be_invalid_after_species_hacked_in() {
+        let mut pkx = Pk8::new(TEST_EKX);
+        pkx.set_species(types::Species(types::Species::MAX_GEN8.0 + 1));
+        assert!(!pkx.is_valid());
+    }
+
+    #[test]
+    fn should_be_valid_with_a_move_added_after_gen6() {
+        let mut pkx = Pk8::new(TEST_EKX);
+        pkx.set_moves(
+            types::Move(700),
+            types::Move::NONE,
+            types::Move::NONE,
+            types::Move::NONE,
+        );
+        pkx.set_checksum(pkx.calculate_checksum());
+        assert!(pkx.is_valid());
+    }
+
+    #[test]
+    fn should_be_invalid_after_move_hacked_in() {
+        let mut pkx = Pk8::new(TEST_EKX);
+        pkx.set_moves(
+            types::Move(types::Move::MAX_GEN8.0 + 1),
+            types::Move::NONE,
+            types::Move::NONE,
+            types::Move::NONE,
+        );
+        pkx.set_checksum(pkx.calculate_checksum());
+        assert!(!pkx.is_valid());
+    }
+
+    #[test]
+    fn should_read_nickname() {
+        let pkx = Pk8::new(TEST_EKX);
+        assert_eq!(pkx.nickname(), "Galar Pal");
+    }
+
+    #[test]
+    fn should_read_ot_name() {
+        let pkx = Pk8::new(TEST_EKX);
+        assert_eq!(pkx.ot_name(), "Leon");
+    }
+
+    #[test]
+    fn should_round_trip_encryption_unmodified() {
+        let pkx = Pk8::new(TEST_EKX);
+        let reencrypted = pkx.encrypt();
+        let roundtripped = Pk8::new(reencrypted);
+        assert_eq!(roundtripped.get_slice(), pkx.get_slice());
+    }
+
+    #[test]
+    fn should_round_trip_encryption_after_mutation() {
+        let mut pkx = Pk8::new(TEST_EKX);
+        let evs = types::Stats {
+            hp: 4,
+            atk: 252,
+            def: 0,
+            spa: 0,
+            spd: 0,
+            spe: 252,
+        };
+        pkx.set_species(types::Species::MEW);
+        pkx.set_evs(evs);
+        pkx.set_moves(
+            types::Move::TRANSFORM,
+            types::Move::NONE,
+            types::Move::NONE,
+            types::Move::NONE,
+        );
+        pkx.set_iv32(pkx.iv32());
+
+        let reencrypted = pkx.encrypt();
+        let roundtripped = Pk8::new(reencrypted);
+
+        assert_eq!(roundtripped.species(), types::Species::MEW);
+        assert_eq!(roundtripped.evs(), evs);
+    }
+
+    #[test]
+    fn should_round_trip_ot_name_without_truncation() {
+        let mut pkx = Pk8::new(TEST_EKX);
+        pkx.set_ot_name("Ditto is ");
+        assert_eq!(pkx.ot_name(), "Ditto is ");
+    }
+}
@@ -0,0 +1,507 @@
+use super::{
+    pkx::{read_utf16_string, write_utf16_string, Name, Pkx},
+    poke_crypto, types,
+};
+use core::convert::TryInto;
+use no_std_io::{Reader, Writer};
+use safe_transmute::TriviallyTransmutable;
+
+pub type Pk9Bytes = [u8; Pk9::STORED_SIZE];
+
+pub struct Pk9 {
+    data: Pk9Bytes,
+}
+
+impl Pk9 {
+    pub const STORED_SIZE: usize = 328;
+    pub const BLOCK_SIZE: usize = 80;
+
+    pub fn new(data: [u8; Pk9::STORED_SIZE]) -> Self {
+        let seed_bytes: [u8; 4] = data[0..4].try_into().unwrap();
+        let seed = u32::from_le_bytes(seed_bytes);
+        Self {
+            data: poke_crypto::decrypt::<{ Pk9::STORED_SIZE }, { Pk9::BLOCK_SIZE }>(data, seed),
+        }
+    }
+
+    /// Builds a `Pk9` directly from already-decrypted bytes, skipping the
+    /// block-shuffle/PRNG-XOR step `new` performs on stored data. Used when
+    /// constructing an entity from scratch, e.g. during [`super::convert`].
+    pub(crate) fn from_decrypted(data: Pk9Bytes) -> Self {
+        Self { data }
+    }
+
+    /// Re-encrypts this entity back into its stored `.ek9` form, recomputing
+    /// the checksum over the block region first.
+    pub fn encrypt(&self) -> Pk9Bytes {
+        let mut data = self.data;
+        let checksum = self.calculate_checksum();
+        data[0x06..0x08].copy_from_slice(&checksum.to_le_bytes());
+        poke_crypto::encrypt::<{ Pk9::STORED_SIZE }, { Pk9::BLOCK_SIZE }>(data)
+    }
+}
+
+impl Reader for Pk9 {
+    fn get_slice(&self) -> &[u8] {
+        &self.data
+    }
+}
+
+impl Writer for Pk9 {
+    fn get_mut_slice(&mut self) -> &mut [u8] {
+        &mut self.data
+    }
+}
+
+impl Pkx for Pk9 {
+    fn encryption_constant(&self) -> u32 {
+        self.default_read_le(0x00)
+    }
+
+    fn set_encryption_constant(&mut self, encryption_constant: u32) {
+        self.default_write_le(0x00, encryption_constant);
+    }
+
+    fn checksum(&self) -> u16 {
+        self.default_read_le(0x06)
+    }
+
+    fn set_checksum(&mut self, checksum: u16) {
+        self.default_write_le(0x06, checksum);
+    }
+
+    fn species(&self) -> types::Species {
+        self.default_read_le::<u16>(0x08).into()
+    }
+
+    fn set_species(&mut self, species: types::Species) {
+        self.default_write_le::<u16>(0x08, species.into());
+    }
+
+    fn tid(&self) -> u16 {
+        self.default_read_le(0x0C)
+    }
+
+    fn set_tid(&mut self, tid: u16) {
+        self.default_write_le(0x0C, tid);
+    }
+
+    fn sid(&self) -> u16 {
+        self.default_read_le(0x0E)
+    }
+
+    fn set_sid(&mut self, sid: u16) {
+        self.default_write_le(0x0E, sid);
+    }
+
+    fn ability(&self) -> types::Ability {
+        let ability: u8 = self.default_read(0x14);
+        (ability as u16).into()
+    }
+
+    fn set_ability(&mut self, ability: types::Ability) {
+        self.default_write(0x14, u16::from(ability) as u8);
+    }
+
+    fn ability_number(&self) -> types::AbilityNumber {
+        self.default_read::<u8>(0x15).into()
+    }
+
+    fn set_ability_number(&mut self, ability_number: types::AbilityNumber) {
+        self.default_write(0x15, u8::from(ability_number));
+    }
+
+    fn pid(&self) -> u32 {
+        self.default_read_le(0x18)
+    }
+
+    fn set_pid(&mut self, pid: u32) {
+        self.default_write_le(0x18, pid);
+    }
+
+    fn nature(&self) -> types::Nature {
+        self.default_read::<u8>(0x1C).into()
+    }
+
+    fn set_nature(&mut self, nature: types::Nature) {
+        self.default_write(0x1C, u8::from(nature));
+    }
+
+    fn gender(&self) -> types::Gender {
+        let byte = self.default_read::<u8>(0x1D);
+        ((byte >> 1) & 3).into()
+    }
+
+    fn set_gender(&mut self, gender: types::Gender) {
+        let byte = self.default_read::<u8>(0x1D);
+        let gender_bits = u8::from(gender) & 3;
+        self.default_write(0x1D, (byte & !0x06) | (gender_bits << 1));
+    }
+
+    fn evs(&self) -> types::Stats {
+        types::Stats {
+            hp: self.default_read(0x1E),
+            atk: self.default_read(0x1F),
+            def: self.default_read(0x20),
+            spa: self.default_read(0x21),
+            spd: self.default_read(0x22),
+            spe: self.default_read(0x23),
+        }
+    }
+
+    fn set_evs(&mut self, evs: types::Stats) {
+        self.default_write(0x1E, evs.hp);
+        self.default_write(0x1F, evs.atk);
+        self.default_write(0x20, evs.def);
+        self.default_write(0x21, evs.spa);
+        self.default_write(0x22, evs.spd);
+        self.default_write(0x23, evs.spe);
+    }
+
+    // Block A (species..evs, above) keeps Pk6's offsets: it starts at the
+    // same 0x08 regardless of generation and every field on it falls well
+    // inside the first BLOCK_SIZE (80) bytes either way. Block B onward
+    // moved: Pk9 reuses Pk8's 80-byte blocks, 24 bytes wider than Pk6's, so
+    // each later block starts 24/48/72 bytes later than its Pk6 counterpart.
+
+    fn move1(&self) -> types::Move {
+        self.default_read::<u16>(0x72).into()
+    }
+
+    fn move2(&self) -> types::Move {
+        self.default_read::<u16>(0x74).into()
+    }
+
+    fn move3(&self) -> types::Move {
+        self.default_read::<u16>(0x76).into()
+    }
+
+    fn move4(&self) -> types::Move {
+        self.default_read::<u16>(0x78).into()
+    }
+
+    fn set_moves(
+        &mut self,
+        move1: types::Move,
+        move2: types::Move,
+        move3: types::Move,
+        move4: types::Move,
+    ) {
+        self.default_write::<u16>(0x72, move1.into());
+        self.default_write::<u16>(0x74, move2.into());
+        self.default_write::<u16>(0x76, move3.into());
+        self.default_write::<u16>(0x78, move4.into());
+    }
+
+    fn iv32(&self) -> u32 {
+        self.default_read_le(0x8C)
+    }
+
+    fn set_iv32(&mut self, iv32: u32) {
+        self.default_write_le(0x8C, iv32);
+    }
+
+    fn ht_friendship(&self) -> u32 {
+        self.default_read(0xD2)
+    }
+
+    fn set_ht_friendship(&mut self, ht_friendship: u32) {
+        self.default_write(0xD2, ht_friendship);
+    }
+
+    fn ot_friendship(&self) -> u32 {
+        self.default_read(0x112)
+    }
+
+    fn set_ot_friendship(&mut self, ot_friendship: u32) {
+        self.default_write(0x112, ot_friendship);
+    }
+
+    fn language(&self) -> types::Language {
+        self.default_read::<u8>(0x12B).into()
+    }
+
+    fn set_language(&mut self, language: types::Language) {
+        self.default_write(0x12B, u8::from(language));
+    }
+
+    fn nickname(&self) -> Name {
+        read_utf16_string(self.get_slice(), 0x58, 12)
+    }
+
+    fn set_nickname(&mut self, nickname: &str) {
+        write_utf16_string(self.get_mut_slice(), 0x58, 13, nickname);
+    }
+
+    fn ot_name(&self) -> Name {
+        read_utf16_string(self.get_slice(), 0xF8, 9)
+    }
+
+    fn set_ot_name(&mut self, ot_name: &str) {
+        write_utf16_string(self.get_mut_slice(), 0xF8, 10, ot_name);
+    }
+
+    fn ht_name(&self) -> Name {
+        read_utf16_string(self.get_slice(), 0xA8, 8)
+    }
+
+    fn set_ht_name(&mut self, ht_name: &str) {
+        write_utf16_string(self.get_mut_slice(), 0xA8, 9, ht_name);
+    }
+
+    fn species_max(&self) -> types::Species {
+        types::Species::MAX_GEN9
+    }
+
+    fn move_max(&self) -> types::Move {
+        types::Move::MAX_GEN9
+    }
+
+    fn ability_max(&self) -> types::Ability {
+        types::Ability::MAX_GEN9
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Pk9Data(Pk9Bytes);
+
+// This is safe because the bytes in Pk9Data can be anything
+unsafe impl TriviallyTransmutable for Pk9Data {}
+
+impl From<Pk9Data> for Pk9 {
+    fn from(data: Pk9Data) -> Self {
+        Self::new(data.0)
+    }
+}
+
+impl Default for Pk9Data {
+    fn default() -> Self {
+        Self([0; Pk9::STORED_SIZE])
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const TEST_EKX: Pk9Bytes = [
+        0xa1, 0x20, 0x7e, 0x4f, 0x00, 0x00, 0x78, 0xc9, 0x65, 0x64, 0xd9, 0x0b, 0x5c, 0x95, 0x69,
+        0x7d, 0xd0, 0x48, 0x8c, 0xce, 0x25, 0x5e, 0x87, 0xdf, 0xbe, 0xb5, 0x43, 0xaa, 0xd6, 0x86,
+        0x1b, 0xf0, 0xa0, 0x9f, 0x33, 0xd0, 0x92, 0x39, 0x33, 0x8f, 0xcd, 0xd6, 0xd6, 0x68, 0x84,
+        0x11, 0x85, 0x46, 0x81, 0x74, 0xfd, 0x66, 0x35, 0xec, 0x44, 0xb6, 0xf5, 0x31, 0x88, 0x98,
+        0x55, 0x4f, 0x92, 0xa1, 0xc0, 0x0d, 0xf7, 0x93, 0xbb, 0x66, 0x32, 0x4e, 0x7a, 0xe3, 0x41,
+        0x2d, 0xaa, 0xde, 0x82, 0x2b, 0xa2, 0xd9, 0xe4, 0xcb, 0x56, 0x1e, 0xa7, 0xf4, 0xc1, 0xc9,
+        0x3c, 0xb0, 0xa5, 0xa6, 0x09, 0xc2, 0xce, 0x06, 0x18, 0xe6, 0x39, 0x99, 0x01, 0xbe, 0xd4,
+        0x25, 0x8a, 0xaa, 0xae, 0xab, 0xd1, 0x70, 0xd1, 0xac, 0xfe, 0x5a, 0x1f, 0x0b, 0xd4, 0xd7,
+        0xe1, 0xcb, 0x8e, 0x41, 0xef, 0xf3, 0xf5, 0xbb, 0x9b, 0x84, 0x94, 0xd2, 0xc5, 0x01, 0x65,
+        0xfb, 0xbb, 0xe3, 0x7f, 0x2f, 0xd7, 0x7b, 0x87, 0xba, 0x00, 0x31, 0xea, 0x02, 0x6b, 0x21,
+        0x2f, 0xb0, 0x57, 0x39, 0xea, 0x09, 0x9b, 0x3a, 0x0c, 0x01, 0x39, 0x17, 0xa7, 0xde, 0x58,
+        0xf1, 0x61, 0x5e, 0xde, 0x1e, 0x2d, 0xd8, 0xb3, 0xef, 0xf1, 0x41, 0x60, 0xc7, 0x83, 0x13,
+        0x58, 0xba, 0x5f, 0x31, 0xcd, 0x1e, 0x10, 0xf6, 0x57, 0xa8, 0x46, 0x5a, 0x0b, 0xf4, 0x32,
+        0xd8, 0x2a, 0x69, 0x3d, 0xca, 0xf5, 0x9c, 0xe0, 0x5d, 0x01, 0x38, 0xc0, 0xa7, 0x61, 0xff,
+        0xe8, 0x0d, 0x2e, 0x9f, 0x47, 0xc2, 0x89, 0x35, 0xb4, 0x07, 0xfe, 0x2e, 0xcb, 0x37, 0x67,
+        0x0a, 0xc6, 0xa8, 0xb5, 0xaa, 0x7c, 0xfd, 0x4a, 0x07, 0x26, 0xe7, 0xd2, 0xdf, 0xac, 0x3f,
+        0xb5, 0xa6, 0x74, 0x74, 0x97, 0xad, 0x0d, 0xcd, 0xd7, 0xd1, 0x5d, 0xfe, 0xa4, 0x00, 0x7b,
+        0x01, 0x3e, 0x0a, 0xec, 0x1f, 0x92, 0xc7, 0xa1, 0xe1, 0x44, 0xeb, 0xa8, 0xd7, 0x6e, 0x22,
+        0xd4, 0x64, 0x4d, 0xd9, 0xc1, 0x9a, 0x60, 0x53, 0x10, 0x0f, 0x5f, 0x13, 0x02, 0x4e, 0x18,
+        0x34, 0xf0, 0x64, 0xe3, 0x7d, 0x5f, 0xc7, 0x19, 0x32, 0x77, 0xdc, 0xca, 0xd1, 0xde, 0xa0,
+        0x6c, 0xde, 0x94, 0x64, 0x59, 0x11, 0xb7, 0x75, 0xb5, 0x28, 0x08, 0xe9, 0x51, 0x67, 0xb3,
+        0xdc, 0x98, 0x49, 0x35, 0xa9, 0x50, 0xd4, 0xba, 0xb3, 0x25, 0x4c, 0x7f, 0x1d,
+    ];
+
+    #[test]
+    fn should_decrypt() {
+        let result: Pk9Bytes = [
+            0xa1, 0x20, 0x7e, 0x4f, 0x00, 0x00, 0x78, 0xc9, 0x8e, 0x03, 0x00, 0x00, 0x03, 0xd9,
+            0x0a, 0x1a, 0x00, 0x00, 0x00, 0x00, 0x0b, 0x02, 0x00, 0x00, 0xa9, 0xcb, 0xed, 0x0f,
+            0x05, 0x02, 0x04, 0x00, 0xfc, 0x00, 0xfc, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x50, 0x00, 0x61, 0x00, 0x6c, 0x00, 0x64, 0x00, 0x65, 0x00,
+            0x61, 0x00, 0x20, 0x00, 0x42, 0x00, 0x75, 0x00, 0x64, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x05, 0x00, 0x06, 0x00, 0x07, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x31, 0xc6, 0x18, 0x23, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x4e, 0x00, 0x65, 0x00,
+            0x6d, 0x00, 0x6f, 0x00, 0x6e, 0x00, 0x61, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        ];
+
+        let pkx = Pk9::new(TEST_EKX);
+        assert_eq!(pkx.get_slice(), result)
+    }
+
+    #[test]
+    fn pk9_data_size_should_be_328() {
+        assert_eq!(core::mem::size_of::<Pk9Data>(), Pk9::STORED_SIZE);
+    }
+
+    #[test]
+    fn should_read_species() {
+        let pkx = Pk9::new(TEST_EKX);
+        // Above the Generation 8 cap (898) but within Generation 9's (1010):
+        // this is the case the old hardcoded Species::MAX bound got wrong.
+        assert_eq!(pkx.species(), types::Species(910));
+    }
+
+    #[test]
+    fn should_read_pid() {
+        let pkx = Pk9::new(TEST_EKX);
+        assert_eq!(pkx.pid(), 0x0FEDCBA9)
+    }
+
+    #[test]
+    fn should_read_tid() {
+        let pkx = Pk9::new(TEST_EKX);
+        assert_eq!(pkx.tid(), 55555)
+    }
+
+    #[test]
+    fn should_read_sid() {
+        let pkx = Pk9::new(TEST_EKX);
+        assert_eq!(pkx.sid(), 6666)
+    }
+
+    #[test]
+    fn should_read_nature() {
+        let pkx = Pk9::new(TEST_EKX);
+        assert_eq!(pkx.nature(), types::Nature(5));
+    }
+
+    #[test]
+    fn should_read_ability_number() {
+        let pkx = Pk9::new(TEST_EKX);
+        assert_eq!(pkx.ability_number(), types::AbilityNumber::SECOND)
+    }
+
+    #[test]
+    fn should_read_gender() {
+        let pkx = Pk9::new(TEST_EKX);
+        assert_eq!(pkx.gender(), types::Gender::Female)
+    }
+
+    #[test]
+    fn should_read_move1() {
+        let pkx = Pk9::new(TEST_EKX);
+        assert_eq!(pkx.move1(), types::Move(5))
+    }
+
+    #[test]
+    fn should_read_ivs() {
+        let pkx = Pk9::new(TEST_EKX);
+        let stats = types::Stats {
+            hp: 17,
+            atk: 17,
+            def: 17,
+            spa: 17,
+            spd: 17,
+            spe: 17,
+        };
+        assert_eq!(pkx.ivs(), stats)
+    }
+
+    #[test]
+    fn should_read_evs() {
+        let pkx = Pk9::new(TEST_EKX);
+        let stats = types::Stats {
+            hp: 4,
+            atk: 0,
+            def: 252,
+            spa: 0,
+            spd: 252,
+            spe: 0,
+        };
+        assert_eq!(pkx.evs(), stats)
+    }
+
+    #[test]
+    fn should_read_checksum() {
+        let pkx = Pk9::new(TEST_EKX);
+        assert_eq!(pkx.checksum(), 0xc978);
+    }
+
+    #[test]
+    fn should_calculate_matching_checksum() {
+        let pkx = Pk9::new(TEST_EKX);
+        assert_eq!(pkx.calculate_checksum(), pkx.checksum());
+    }
+
+    #[test]
+    fn should_be_valid() {
+        let pkx = Pk9::new(TEST_EKX);
+        assert!(pkx.is_valid());
+    }
+
+    #[test]
+    fn should_be_invalid_after_species_hacked_in() {
+        let mut pkx = Pk9::new(TEST_EKX);
+        pkx.set_species(types::Species(types::Species::MAX_GEN9.0 + 1));
+        assert!(!pkx.is_valid());
+    }
+
+    #[test]
+    fn should_read_nickname() {
+        let pkx = Pk9::new(TEST_EKX);
+        assert_eq!(pkx.nickname(), "Paldea Bud");
+    }
+
+    #[test]
+    fn should_read_ot_name() {
+        let pkx = Pk9::new(TEST_EKX);
+        assert_eq!(pkx.ot_name(), "Nemona");
+    }
+
+    #[test]
+    fn should_round_trip_encryption_unmodified() {
+        let pkx = Pk9::new(TEST_EKX);
+        let reencrypted = pkx.encrypt();
+        let roundtripped = Pk9::new(reencrypted);
+        assert_eq!(roundtripped.get_slice(), pkx.get_slice());
+    }
+
+    #[test]
+    fn should_round_trip_encryption_after_mutation() {
+        let mut pkx = Pk9::new(TEST_EKX);
+        let evs = types::Stats {
+            hp: 4,
+            atk: 252,
+            def: 0,
+            spa: 0,
+            spd: 0,
+            spe: 252,
+        };
+        pkx.set_species(types::Species::MEW);
+        pkx.set_evs(evs);
+        pkx.set_moves(
+            types::Move::TRANSFORM,
+            types::Move::NONE,
+            types::Move::NONE,
+            types::Move::NONE,
+        );
+        pkx.set_iv32(pkx.iv32());
+
+        let reencrypted = pkx.encrypt();
+        let roundtripped = Pk9::new(reencrypted);
+
+        assert_eq!(roundtripped.species(), types::Species::MEW);
+        assert_eq!(roundtripped.evs(), evs);
+    }
+
+    #[test]
+    fn should_round_trip_ot_name_without_truncation() {
+        let mut pkx = Pk9::new(TEST_EKX);
+        pkx.set_ot_name("Ditto is ");
+        assert_eq!(pkx.ot_name(), "Ditto is ");
+    }
+}
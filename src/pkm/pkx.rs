@@ -0,0 +1,245 @@
+use super::poke_crypto;
+use super::types::{Ability, AbilityNumber, Gender, HiddenPower, Language, Move, Nature, Species, Stats};
+use core::char::decode_utf16;
+use no_std_io::{EndianRead, EndianWrite, Reader, Writer};
+
+/// Capacity of the `heapless` strings returned by [`Pkx::nickname`] and
+/// friends: 12 UTF-16 code units, 3 bytes of UTF-8 worst case.
+pub const MAX_NAME_LEN: usize = 36;
+
+pub type Name = heapless::String<MAX_NAME_LEN>;
+
+/// Decodes a null-terminated, fixed-width UTF-16LE string starting at
+/// `offset` into `data`, reading at most `max_units` code units.
+pub(crate) fn read_utf16_string(data: &[u8], offset: usize, max_units: usize) -> Name {
+    let mut name = Name::new();
+    let mut high_surrogate = None;
+    for i in 0..max_units {
+        let unit_offset = offset + i * 2;
+        let Some(bytes) = data.get(unit_offset..unit_offset + 2) else {
+            break;
+        };
+        let unit = u16::from_le_bytes([bytes[0], bytes[1]]);
+        if unit == 0 {
+            break;
+        }
+        if let Some(high) = high_surrogate.take() {
+            if let Some(Ok(c)) = decode_utf16([high, unit]).next() {
+                let _ = name.push(c);
+            }
+            continue;
+        }
+        match decode_utf16([unit]).next() {
+            Some(Ok(c)) => {
+                let _ = name.push(c);
+            }
+            // An unpaired high surrogate: decode_utf16 reports it as an
+            // error on its own, so hold onto it and pair it with the next
+            // unit instead of dropping the character it encodes.
+            Some(Err(_)) => high_surrogate = Some(unit),
+            None => {}
+        }
+    }
+    name
+}
+
+/// Encodes `value` as a null-terminated UTF-16LE string into `data` at
+/// `offset`, truncating to `max_units` code units (including the
+/// terminator) and zero-padding whatever's left of the field.
+pub(crate) fn write_utf16_string(data: &mut [u8], offset: usize, max_units: usize, value: &str) {
+    let mut units = value.encode_utf16();
+    for i in 0..max_units {
+        let unit_offset = offset + i * 2;
+        let Some(bytes) = data.get_mut(unit_offset..unit_offset + 2) else {
+            break;
+        };
+        let unit = if i + 1 < max_units { units.next().unwrap_or(0) } else { 0 };
+        bytes.copy_from_slice(&unit.to_le_bytes());
+    }
+}
+
+/// Common read/write surface shared by every generation's box format.
+///
+/// Each generation implements the required methods against its own byte
+/// offsets; the provided methods are derived values that are computed the
+/// same way regardless of generation, except the `*_max` bounds used by
+/// [`Pkx::is_valid`], which later formats override.
+pub trait Pkx: Reader + Writer {
+    fn default_read<T: EndianRead + Default>(&self, offset: usize) -> T {
+        self.read(offset).unwrap_or_default()
+    }
+
+    fn default_read_le<T: EndianRead + Default>(&self, offset: usize) -> T {
+        self.read_le(offset).unwrap_or_default()
+    }
+
+    fn default_write<T: EndianWrite>(&mut self, offset: usize, value: T) {
+        let _ = self.write(offset, value);
+    }
+
+    fn default_write_le<T: EndianWrite>(&mut self, offset: usize, value: T) {
+        let _ = self.write_le(offset, value);
+    }
+
+    fn encryption_constant(&self) -> u32;
+    fn set_encryption_constant(&mut self, encryption_constant: u32);
+    /// The checksum stored alongside the encryption constant, computed by
+    /// the game when the entity was last saved. Compare against
+    /// [`Pkx::calculate_checksum`] via [`Pkx::is_valid`] to detect corruption.
+    fn checksum(&self) -> u16;
+    fn set_checksum(&mut self, checksum: u16);
+    fn species(&self) -> Species;
+    fn set_species(&mut self, species: Species);
+    fn tid(&self) -> u16;
+    fn set_tid(&mut self, tid: u16);
+    fn sid(&self) -> u16;
+    fn set_sid(&mut self, sid: u16);
+    fn ability(&self) -> Ability;
+    fn set_ability(&mut self, ability: Ability);
+    fn ability_number(&self) -> AbilityNumber;
+    fn set_ability_number(&mut self, ability_number: AbilityNumber);
+    fn pid(&self) -> u32;
+    fn set_pid(&mut self, pid: u32);
+    fn nature(&self) -> Nature;
+    fn set_nature(&mut self, nature: Nature);
+    fn gender(&self) -> Gender;
+    fn set_gender(&mut self, gender: Gender);
+    fn evs(&self) -> Stats;
+    fn set_evs(&mut self, evs: Stats);
+    fn move1(&self) -> Move;
+    fn move2(&self) -> Move;
+    fn move3(&self) -> Move;
+    fn move4(&self) -> Move;
+    fn set_moves(&mut self, move1: Move, move2: Move, move3: Move, move4: Move);
+    fn iv32(&self) -> u32;
+    fn set_iv32(&mut self, iv32: u32);
+    fn ht_friendship(&self) -> u32;
+    fn set_ht_friendship(&mut self, ht_friendship: u32);
+    fn ot_friendship(&self) -> u32;
+    fn set_ot_friendship(&mut self, ot_friendship: u32);
+    fn language(&self) -> Language;
+    fn set_language(&mut self, language: Language);
+
+    /// The nickname given to this Pokémon, decoded from its UTF-16LE
+    /// string region. Offsets are generation-specific, so this has no
+    /// useful default and every format must provide its own.
+    fn nickname(&self) -> Name;
+    fn set_nickname(&mut self, nickname: &str);
+    /// The original trainer's name.
+    fn ot_name(&self) -> Name;
+    fn set_ot_name(&mut self, ot_name: &str);
+    /// The current handler's name, if this entity has been traded.
+    fn ht_name(&self) -> Name;
+    fn set_ht_name(&mut self, ht_name: &str);
+
+    /// The nature actually affecting stat calculation, which may differ
+    /// from the stored [`Pkx::nature`] once "mints" are involved. Formats
+    /// that don't support minting simply return the stored nature.
+    fn minted_nature(&self) -> Nature {
+        self.nature()
+    }
+
+    /// Highest species ID this format's game could have actually produced,
+    /// used by [`Pkx::is_valid`]. Defaults to the Generation 6 cap; later
+    /// formats override this with their own generation's `Species::MAX_*`.
+    fn species_max(&self) -> Species {
+        Species::MAX
+    }
+
+    /// Highest move ID this format's game could have actually produced,
+    /// used by [`Pkx::is_valid`]. Defaults to the Generation 6 cap; later
+    /// formats override this with their own generation's `Move::MAX_*`.
+    fn move_max(&self) -> Move {
+        Move::MAX
+    }
+
+    /// Highest ability ID this format's game could have actually produced,
+    /// used by [`Pkx::is_valid`]. Defaults to the Generation 6 cap; later
+    /// formats override this with their own generation's `Ability::MAX_*`.
+    fn ability_max(&self) -> Ability {
+        Ability::MAX
+    }
+
+    fn ivs(&self) -> Stats {
+        let iv32 = self.iv32();
+        Stats {
+            hp: (iv32 & 0x1F) as u8,
+            atk: ((iv32 >> 5) & 0x1F) as u8,
+            def: ((iv32 >> 10) & 0x1F) as u8,
+            spe: ((iv32 >> 15) & 0x1F) as u8,
+            spa: ((iv32 >> 20) & 0x1F) as u8,
+            spd: ((iv32 >> 25) & 0x1F) as u8,
+        }
+    }
+
+    /// Trainer Shiny Value: `(TID ^ SID) >> 4`.
+    fn tsv(&self) -> u16 {
+        (self.tid() ^ self.sid()) >> 4
+    }
+
+    /// Personality Shiny Value: `((PID >> 16) ^ (PID & 0xFFFF)) >> 4`.
+    fn psv(&self) -> u16 {
+        let pid = self.pid();
+        (((pid >> 16) ^ (pid & 0xFFFF)) >> 4) as u16
+    }
+
+    /// Recomputes the checksum over the block region from the currently
+    /// stored bytes, for comparison against the stored [`Pkx::checksum`].
+    fn calculate_checksum(&self) -> u16 {
+        poke_crypto::checksum(self.get_slice())
+    }
+
+    /// Whether this entity's data is internally consistent: the stored
+    /// checksum matches the data, and the species, moves, ability and
+    /// language are within the range of values the games can actually
+    /// produce. Other fields (nature, ability number, ...) are not
+    /// range-checked. This does not
+    /// guarantee the data is *legal* (a legitimate result of play), only
+    /// that it isn't corrupt or obviously hacked.
+    fn is_valid(&self) -> bool {
+        self.checksum() == self.calculate_checksum()
+            && self.species().0 <= self.species_max().0
+            && self.move1().0 <= self.move_max().0
+            && self.move2().0 <= self.move_max().0
+            && self.move3().0 <= self.move_max().0
+            && self.move4().0 <= self.move_max().0
+            && self.ability().0 <= self.ability_max().0
+            && matches!(
+                self.language(),
+                Language::JAPANESE
+                    | Language::ENGLISH
+                    | Language::FRENCH
+                    | Language::ITALIAN
+                    | Language::GERMAN
+                    | Language::SPANISH
+                    | Language::KOREAN
+                    | Language::CHINESE_SIMPLIFIED
+                    | Language::CHINESE_TRADITIONAL
+            )
+    }
+
+    fn hidden_power(&self) -> HiddenPower {
+        let ivs = self.ivs();
+        let bits = [ivs.hp, ivs.atk, ivs.def, ivs.spe, ivs.spa, ivs.spd];
+        let type_sum: u32 = bits
+            .iter()
+            .enumerate()
+            .map(|(i, iv)| ((*iv as u32) & 1) << i)
+            .sum();
+        HiddenPower::from(((type_sum * 15) / 63) as u8)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn should_round_trip_a_character_outside_the_bmp() {
+        // U+1F600 GRINNING FACE encodes as the surrogate pair 0xD83D 0xDE00,
+        // which needs both units paired back together to decode correctly.
+        let mut data = [0u8; 6];
+        write_utf16_string(&mut data, 0, 3, "\u{1F600}");
+        assert_eq!(read_utf16_string(&data, 0, 2), "\u{1F600}");
+    }
+}
@@ -0,0 +1,115 @@
+//! The block-shuffle + PRNG-XOR encryption scheme used by the `.ek6`/`.pk6`
+//! family of box formats (and their later-generation equivalents).
+//!
+//! The first 8 bytes of a stored entity (encryption constant + checksum)
+//! are never touched. The remainder is split into 4 equally-sized blocks
+//! whose order is determined by `(encryption_constant >> 13) & 0x1F`, and
+//! every `u16` word from there to the end of the buffer is XORed against
+//! a running 32-bit LCG seeded with the encryption constant.
+
+use core::convert::TryInto;
+
+const HEADER_SIZE: usize = 8;
+
+/// All 24 possible orderings of the 4 data blocks, indexed by shift value
+/// `(encryption_constant >> 13) & 0x1F`, taken modulo 24.
+const BLOCK_POSITION: [[usize; 4]; 24] = [
+    [0, 1, 2, 3],
+    [0, 1, 3, 2],
+    [0, 2, 1, 3],
+    [0, 2, 3, 1],
+    [0, 3, 1, 2],
+    [0, 3, 2, 1],
+    [1, 0, 2, 3],
+    [1, 0, 3, 2],
+    [1, 2, 0, 3],
+    [3, 0, 1, 2],
+    [1, 3, 0, 2],
+    [1, 3, 2, 0],
+    [2, 0, 1, 3],
+    [2, 0, 3, 1],
+    [2, 1, 0, 3],
+    [2, 1, 3, 0],
+    [2, 3, 0, 1],
+    [2, 3, 1, 0],
+    [1, 2, 3, 0],
+    [3, 0, 2, 1],
+    [3, 1, 0, 2],
+    [3, 1, 2, 0],
+    [3, 2, 0, 1],
+    [3, 2, 1, 0],
+];
+
+fn shift_value(seed: u32) -> usize {
+    ((seed >> 13) & 0x1F) as usize % BLOCK_POSITION.len()
+}
+
+fn xor_words(data: &mut [u8], seed: u32) {
+    let mut seed = seed;
+    let mut i = HEADER_SIZE;
+    while i + 1 < data.len() {
+        seed = seed.wrapping_mul(0x41C6_4E6D).wrapping_add(0x6073);
+        let word = u16::from_le_bytes([data[i], data[i + 1]]) ^ ((seed >> 16) as u16);
+        let bytes = word.to_le_bytes();
+        data[i] = bytes[0];
+        data[i + 1] = bytes[1];
+        i += 2;
+    }
+}
+
+fn shuffle_blocks<const SIZE: usize, const BLOCK_SIZE: usize>(
+    data: [u8; SIZE],
+    order: [usize; 4],
+) -> [u8; SIZE] {
+    let mut out = data;
+    for (dest, &src) in order.iter().enumerate() {
+        let src_start = HEADER_SIZE + src * BLOCK_SIZE;
+        let dest_start = HEADER_SIZE + dest * BLOCK_SIZE;
+        out[dest_start..dest_start + BLOCK_SIZE]
+            .copy_from_slice(&data[src_start..src_start + BLOCK_SIZE]);
+    }
+    out
+}
+
+fn invert_order(order: [usize; 4]) -> [usize; 4] {
+    let mut inverse = [0usize; 4];
+    for (i, &o) in order.iter().enumerate() {
+        inverse[o] = i;
+    }
+    inverse
+}
+
+/// Decrypts a stored `.ekX` buffer into its plain `.pkX` form.
+pub fn decrypt<const SIZE: usize, const BLOCK_SIZE: usize>(
+    data: [u8; SIZE],
+    seed: u32,
+) -> [u8; SIZE] {
+    let mut dexored = data;
+    xor_words(&mut dexored, seed);
+    shuffle_blocks::<SIZE, BLOCK_SIZE>(dexored, BLOCK_POSITION[shift_value(seed)])
+}
+
+/// Encrypts a plain `.pkX` buffer back into its stored `.ekX` form.
+///
+/// This is the exact inverse of [`decrypt`]: the block order is restored to
+/// its pre-shuffle layout first, then the same LCG keystream (self-inverse
+/// under XOR) is re-applied.
+pub fn encrypt<const SIZE: usize, const BLOCK_SIZE: usize>(data: [u8; SIZE]) -> [u8; SIZE] {
+    let seed_bytes: [u8; 4] = data[0..4].try_into().unwrap();
+    let seed = u32::from_le_bytes(seed_bytes);
+    let shuffled =
+        shuffle_blocks::<SIZE, BLOCK_SIZE>(data, invert_order(BLOCK_POSITION[shift_value(seed)]));
+    let mut encrypted = shuffled;
+    xor_words(&mut encrypted, seed);
+    encrypted
+}
+
+/// Sums the `u16` words in the block region (everything after the 8-byte
+/// header) as a plain 16-bit wrapping sum.
+pub fn checksum(data: &[u8]) -> u16 {
+    data[HEADER_SIZE..]
+        .chunks_exact(2)
+        .fold(0u16, |sum, word| {
+            sum.wrapping_add(u16::from_le_bytes([word[0], word[1]]))
+        })
+}
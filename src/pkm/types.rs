@@ -0,0 +1,259 @@
+//! Small value types shared across the `Pkx` family.
+//!
+//! These are thin newtypes over the raw integers stored in the box format
+//! rather than exhaustive enums, since the underlying ID space (species,
+//! moves, abilities, ...) is large, grows every generation, and a `From<u16>`
+//! conversion needs to be total. Known values get a named associated
+//! constant; anything else is still representable and round-trips through
+//! `u16`/`u8` without loss.
+
+/// A Pokémon species, identified by its National Pokédex number.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Species(pub u16);
+
+impl Species {
+    pub const NONE: Species = Species(0);
+    pub const DITTO: Species = Species(132);
+    pub const MEW: Species = Species(151);
+
+    /// Highest National Pokédex number known as of Generation 6 (ORAS).
+    pub const MAX: Species = Species(721);
+
+    /// Highest National Pokédex number known as of Generation 7 (USUM).
+    pub const MAX_GEN7: Species = Species(807);
+    /// Highest National Pokédex number known as of Generation 8 (SWSH).
+    pub const MAX_GEN8: Species = Species(898);
+    /// Highest National Pokédex number known as of Generation 9 (SV).
+    pub const MAX_GEN9: Species = Species(1010);
+}
+
+impl From<u16> for Species {
+    fn from(value: u16) -> Self {
+        Species(value)
+    }
+}
+
+impl From<Species> for u16 {
+    fn from(value: Species) -> Self {
+        value.0
+    }
+}
+
+/// A move, identified by its move ID.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Move(pub u16);
+
+impl Move {
+    pub const NONE: Move = Move(0);
+    pub const TRANSFORM: Move = Move(144);
+
+    /// Highest move ID known as of Generation 6 (ORAS).
+    pub const MAX: Move = Move(621);
+
+    /// Highest move ID known as of Generation 7 (USUM).
+    pub const MAX_GEN7: Move = Move(728);
+    /// Highest move ID known as of Generation 8 (SWSH).
+    pub const MAX_GEN8: Move = Move(826);
+    /// Highest move ID known as of Generation 9 (SV).
+    pub const MAX_GEN9: Move = Move(919);
+}
+
+impl From<u16> for Move {
+    fn from(value: u16) -> Self {
+        Move(value)
+    }
+}
+
+impl From<Move> for u16 {
+    fn from(value: Move) -> Self {
+        value.0
+    }
+}
+
+/// A nature, indexed 0-24 the same way the games store it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Nature(pub u8);
+
+impl Nature {
+    pub const HARDY: Nature = Nature(0);
+    pub const ADAMANT: Nature = Nature(3);
+    pub const MAX: Nature = Nature(24);
+}
+
+impl From<u8> for Nature {
+    fn from(value: u8) -> Self {
+        Nature(value)
+    }
+}
+
+impl From<Nature> for u8 {
+    fn from(value: Nature) -> Self {
+        value.0
+    }
+}
+
+/// An ability, identified by its ability ID.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Ability(pub u16);
+
+impl Ability {
+    pub const NONE: Ability = Ability(0);
+    pub const IMPOSTER: Ability = Ability(150);
+
+    /// Highest ability ID known as of Generation 6 (ORAS).
+    pub const MAX: Ability = Ability(191);
+
+    /// Highest ability ID known as of Generation 7 (USUM).
+    pub const MAX_GEN7: Ability = Ability(232);
+    /// Highest ability ID known as of Generation 8 (SWSH).
+    pub const MAX_GEN8: Ability = Ability(267);
+    /// Highest ability ID known as of Generation 9 (SV).
+    pub const MAX_GEN9: Ability = Ability(298);
+}
+
+impl From<u16> for Ability {
+    fn from(value: u16) -> Self {
+        Ability(value)
+    }
+}
+
+impl From<Ability> for u16 {
+    fn from(value: Ability) -> Self {
+        value.0
+    }
+}
+
+/// Which of a species' ability slots is active, stored as a single bit
+/// (1 and 2 for the regular slots, 4 for the hidden one).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct AbilityNumber(pub u8);
+
+impl AbilityNumber {
+    pub const FIRST: AbilityNumber = AbilityNumber(1);
+    pub const SECOND: AbilityNumber = AbilityNumber(2);
+    pub const HIDDEN: AbilityNumber = AbilityNumber(4);
+}
+
+impl From<u8> for AbilityNumber {
+    fn from(value: u8) -> Self {
+        AbilityNumber(value)
+    }
+}
+
+impl From<AbilityNumber> for u8 {
+    fn from(value: AbilityNumber) -> Self {
+        value.0
+    }
+}
+
+/// A Pokémon's gender.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Gender {
+    Male,
+    Female,
+    Genderless,
+}
+
+impl From<u8> for Gender {
+    fn from(value: u8) -> Self {
+        match value {
+            0 => Gender::Male,
+            1 => Gender::Female,
+            _ => Gender::Genderless,
+        }
+    }
+}
+
+impl From<Gender> for u8 {
+    fn from(value: Gender) -> Self {
+        match value {
+            Gender::Male => 0,
+            Gender::Female => 1,
+            Gender::Genderless => 2,
+        }
+    }
+}
+
+/// The language a save/entity was created under.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Language(pub u8);
+
+impl Language {
+    pub const NONE: Language = Language(0);
+    pub const JAPANESE: Language = Language(1);
+    pub const ENGLISH: Language = Language(2);
+    pub const FRENCH: Language = Language(3);
+    pub const ITALIAN: Language = Language(4);
+    pub const GERMAN: Language = Language(5);
+    pub const SPANISH: Language = Language(7);
+    pub const KOREAN: Language = Language(8);
+    pub const CHINESE_SIMPLIFIED: Language = Language(9);
+    pub const CHINESE_TRADITIONAL: Language = Language(10);
+}
+
+impl From<u8> for Language {
+    fn from(value: u8) -> Self {
+        Language(value)
+    }
+}
+
+impl From<Language> for u8 {
+    fn from(value: Language) -> Self {
+        value.0
+    }
+}
+
+/// The hidden power type, derived from the low bit of each IV.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum HiddenPower {
+    Fighting,
+    Flying,
+    Poison,
+    Ground,
+    Rock,
+    Bug,
+    Ghost,
+    Steel,
+    Fire,
+    Water,
+    Grass,
+    Electric,
+    Psychic,
+    Ice,
+    Dragon,
+    Dark,
+}
+
+impl From<u8> for HiddenPower {
+    fn from(value: u8) -> Self {
+        match value {
+            0 => HiddenPower::Fighting,
+            1 => HiddenPower::Flying,
+            2 => HiddenPower::Poison,
+            3 => HiddenPower::Ground,
+            4 => HiddenPower::Rock,
+            5 => HiddenPower::Bug,
+            6 => HiddenPower::Ghost,
+            7 => HiddenPower::Steel,
+            8 => HiddenPower::Fire,
+            9 => HiddenPower::Water,
+            10 => HiddenPower::Grass,
+            11 => HiddenPower::Electric,
+            12 => HiddenPower::Psychic,
+            13 => HiddenPower::Ice,
+            14 => HiddenPower::Dragon,
+            _ => HiddenPower::Dark,
+        }
+    }
+}
+
+/// The six core stats, used for both IVs and EVs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Stats {
+    pub hp: u8,
+    pub atk: u8,
+    pub def: u8,
+    pub spa: u8,
+    pub spd: u8,
+    pub spe: u8,
+}